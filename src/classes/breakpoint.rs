@@ -0,0 +1,37 @@
+/// A `bulma`
+/// [breakpoint](https://bulma.io/documentation/overview/responsiveness/),
+/// used to apply classes only at certain screen widths.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Breakpoint {
+    /// Up to 768px.
+    Mobile,
+    /// From 769px.
+    Tablet,
+    /// Up to 1023px.
+    Touch,
+    /// From 1024px.
+    Desktop,
+    /// From 1216px.
+    Widescreen,
+    /// From 1408px.
+    FullHd,
+}
+
+impl Breakpoint {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Breakpoint::Mobile => "mobile",
+            Breakpoint::Tablet => "tablet",
+            Breakpoint::Touch => "touch",
+            Breakpoint::Desktop => "desktop",
+            Breakpoint::Widescreen => "widescreen",
+            Breakpoint::FullHd => "fullhd",
+        }
+    }
+
+    /// Append this breakpoint as a suffix to the `class`, for example
+    /// `is-half` becomes `is-half-tablet`.
+    pub fn suffix(&self, class: &str) -> String {
+        format!("{}-{}", class, self.to_str())
+    }
+}
@@ -0,0 +1,116 @@
+use super::{
+    Breakpoint, Display, Flexbox, Spacing, SpacingLevel, SpacingSide, TextAlignment, TextSize,
+    TextTransform, TextWeight, Visibility,
+};
+
+/// A builder which combines `bulma` helper classes, for use with the
+/// `classes` property of the components, or directly as the `class`
+/// of an element.
+///
+/// ```
+/// use yew_bulma::classes::{
+///     Breakpoint, Classes, Display, SpacingLevel, SpacingSide, TextAlignment,
+/// };
+///
+/// let classes: Vec<String> = Classes::new()
+///     .display(Display::Flex)
+///     .margin(SpacingSide::Top, SpacingLevel::Is2)
+///     .text_alignment_at(TextAlignment::Centered, Breakpoint::Mobile)
+///     .class("my-class")
+///     .into();
+///
+/// assert_eq!(
+///     classes,
+///     vec!["is-flex", "mt-2", "has-text-centered-mobile", "my-class"]
+/// );
+/// ```
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Classes {
+    classes: Vec<String>,
+}
+
+impl Classes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an arbitrary `class`.
+    pub fn class<C: Into<String>>(mut self, class: C) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    pub fn visibility(self, visibility: Visibility) -> Self {
+        self.class(visibility.to_class())
+    }
+
+    /// Apply the `visibility` only at the specified `breakpoint`. Has
+    /// no effect if `bulma` has no such class.
+    pub fn visibility_at(mut self, visibility: Visibility, breakpoint: Breakpoint) -> Self {
+        self.classes
+            .extend(visibility.to_breakpoint_class(breakpoint));
+        self
+    }
+
+    pub fn display(self, display: Display) -> Self {
+        self.class(display.to_class())
+    }
+
+    /// Apply the `display` only at the specified `breakpoint`.
+    pub fn display_at(self, display: Display, breakpoint: Breakpoint) -> Self {
+        self.class(display.to_breakpoint_class(breakpoint))
+    }
+
+    pub fn spacing(self, spacing: Spacing) -> Self {
+        self.class(spacing.to_class())
+    }
+
+    pub fn margin(self, side: SpacingSide, level: SpacingLevel) -> Self {
+        self.spacing(Spacing::margin(side, level))
+    }
+
+    pub fn padding(self, side: SpacingSide, level: SpacingLevel) -> Self {
+        self.spacing(Spacing::padding(side, level))
+    }
+
+    pub fn text_size(self, size: TextSize) -> Self {
+        self.class(size.to_class())
+    }
+
+    /// Apply the text `size` only at the specified `breakpoint`.
+    pub fn text_size_at(self, size: TextSize, breakpoint: Breakpoint) -> Self {
+        self.class(size.to_breakpoint_class(breakpoint))
+    }
+
+    pub fn text_weight(self, weight: TextWeight) -> Self {
+        self.class(weight.to_class())
+    }
+
+    pub fn text_alignment(self, alignment: TextAlignment) -> Self {
+        self.class(alignment.to_class())
+    }
+
+    /// Apply the text `alignment` only at the specified `breakpoint`.
+    pub fn text_alignment_at(self, alignment: TextAlignment, breakpoint: Breakpoint) -> Self {
+        self.class(alignment.to_breakpoint_class(breakpoint))
+    }
+
+    pub fn text_transform(self, transform: TextTransform) -> Self {
+        self.class(transform.to_class())
+    }
+
+    pub fn flexbox(self, flexbox: Flexbox) -> Self {
+        self.class(flexbox.to_class())
+    }
+
+    /// The combined classes.
+    pub fn build(self) -> Vec<String> {
+        self.classes
+    }
+}
+
+impl From<Classes> for Vec<String> {
+    fn from(classes: Classes) -> Self {
+        classes.build()
+    }
+}
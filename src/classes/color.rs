@@ -1,60 +1,148 @@
-#[derive(PartialEq, Clone, Debug)]
-#[allow(dead_code)]
-pub enum Color {
+/// One of `bulma`'s main
+/// [colors](https://bulma.io/documentation/overview/colors/), which
+/// can be used to color elements and components such as buttons and
+/// notifications.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum MainColor {
     White,
     Black,
     Light,
     Dark,
     Primary,
-    Info,
     Link,
+    Info,
     Success,
     Warning,
     Danger,
+}
+
+impl MainColor {
+    pub fn to_str(&self) -> &str {
+        match self {
+            MainColor::White => "white",
+            MainColor::Black => "black",
+            MainColor::Light => "light",
+            MainColor::Dark => "dark",
+            MainColor::Primary => "primary",
+            MainColor::Link => "link",
+            MainColor::Info => "info",
+            MainColor::Success => "success",
+            MainColor::Warning => "warning",
+            MainColor::Danger => "danger",
+        }
+    }
+
+    /// Whether `bulma` has light and dark versions of this color
+    /// (`has-text-primary-light`, `has-background-danger-dark`), and
+    /// the `is-light` style for elements of this color. This is only
+    /// the case for the colors which aren't shades of grey.
+    pub fn has_versions(&self) -> bool {
+        !matches!(
+            self,
+            MainColor::White | MainColor::Black | MainColor::Light | MainColor::Dark
+        )
+    }
+
+    /// The color class of an element or component which supports
+    /// `bulma`'s color modifiers, such as `is-primary` for a button,
+    /// notification, tag, message, navbar or panel.
+    pub fn class(&self) -> String {
+        format!("is-{}", self.to_str())
+    }
+
+    /// The `is-light` class to apply alongside [MainColor::class]
+    /// for the light style of a button, notification, tag or
+    /// message, or `None` if this color has no light style.
+    pub fn light_class(&self) -> Option<String> {
+        self.has_versions().then(|| "is-light".to_string())
+    }
+}
+
+/// One of `bulma`'s shades of grey, which can only be used to color
+/// text and backgrounds.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Shade {
     BlackBis,
     BlackTer,
     GreyDarker,
     GreyDark,
     Grey,
     GreyLight,
+    GreyLighter,
     WhiteTer,
     WhiteBis,
 }
 
-#[allow(dead_code)]
-impl Color {
+impl Shade {
     pub fn to_str(&self) -> &str {
         match self {
-            Color::White => "white",
-            Color::Black => "black",
-            Color::Light => "light",
-            Color::Dark => "dark",
-            Color::Primary => "primary",
-            Color::Info => "info",
-            Color::Link => "link",
-            Color::Success => "success",
-            Color::Warning => "warning",
-            Color::Danger => "danger",
-            Color::BlackBis => "black-bis",
-            Color::BlackTer => "black-ter",
-            Color::GreyDarker => "grey-darker",
-            Color::GreyDark => "grey-dark",
-            Color::Grey => "grey",
-            Color::GreyLight => "grey-light",
-            Color::WhiteTer => "white-ter",
-            Color::WhiteBis => "white-bis",
+            Shade::BlackBis => "black-bis",
+            Shade::BlackTer => "black-ter",
+            Shade::GreyDarker => "grey-darker",
+            Shade::GreyDark => "grey-dark",
+            Shade::Grey => "grey",
+            Shade::GreyLight => "grey-light",
+            Shade::GreyLighter => "grey-lighter",
+            Shade::WhiteTer => "white-ter",
+            Shade::WhiteBis => "white-bis",
+        }
+    }
+}
+
+/// A color for the `bulma`
+/// [color helpers](https://bulma.io/documentation/helpers/color-helpers/),
+/// which color text and backgrounds.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Color {
+    Main(MainColor),
+    /// The light version of a [MainColor], see
+    /// [MainColor::has_versions].
+    MainLight(MainColor),
+    /// The dark version of a [MainColor], see
+    /// [MainColor::has_versions].
+    MainDark(MainColor),
+    Shade(Shade),
+}
+
+impl Color {
+    /// The name of this color used in `bulma`'s helper classes, or
+    /// `None` if `bulma` has no such version of the color.
+    fn name(&self) -> Option<String> {
+        match self {
+            Color::Main(color) => Some(color.to_str().to_string()),
+            Color::MainLight(color) => color
+                .has_versions()
+                .then(|| format!("{}-light", color.to_str())),
+            Color::MainDark(color) => color
+                .has_versions()
+                .then(|| format!("{}-dark", color.to_str())),
+            Color::Shade(shade) => Some(shade.to_str().to_string()),
         }
     }
 
-    pub fn text_class(&self) -> String {
-        format!("has-text-{0}", self.to_str())
+    /// The class which applies this color to text, for example
+    /// `has-text-primary-dark`, or `None` if `bulma` has no such
+    /// class.
+    pub fn text_class(&self) -> Option<String> {
+        self.name().map(|name| format!("has-text-{}", name))
+    }
+
+    /// The class which applies this color to the background, for
+    /// example `has-background-grey-lighter`, or `None` if `bulma`
+    /// has no such class.
+    pub fn background_class(&self) -> Option<String> {
+        self.name().map(|name| format!("has-background-{}", name))
     }
+}
 
-    pub fn background_class(&self) -> String {
-        format!("has-background-{0}", self.to_str())
+impl From<MainColor> for Color {
+    fn from(color: MainColor) -> Self {
+        Color::Main(color)
     }
+}
 
-    pub fn class(&self) -> String {
-        format!("is-{0}", self.to_str())
+impl From<Shade> for Color {
+    fn from(shade: Shade) -> Self {
+        Color::Shade(shade)
     }
 }
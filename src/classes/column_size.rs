@@ -0,0 +1,101 @@
+use super::Breakpoint;
+
+/// The size of a `bulma`
+/// [column](https://bulma.io/documentation/columns/sizes/), either a
+/// number of twelfths, or a named fraction of its `Columns`.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ColumnSize {
+    /// The column takes the space left over by its siblings.
+    #[default]
+    Auto,
+    /// The column only takes the space it needs.
+    IsNarrow,
+    /// Zero width, which is mostly useful for offsets and for hiding
+    /// a column at specific breakpoints.
+    Is0,
+    Is1,
+    Is2,
+    Is3,
+    Is4,
+    Is5,
+    Is6,
+    Is7,
+    Is8,
+    Is9,
+    Is10,
+    Is11,
+    Is12,
+    IsFull,
+    IsFourFifths,
+    IsThreeQuarters,
+    IsTwoThirds,
+    IsThreeFifths,
+    IsHalf,
+    IsTwoFifths,
+    IsOneThird,
+    IsOneQuarter,
+    IsOneFifth,
+}
+
+impl ColumnSize {
+    /// The name of this size used in `bulma`'s classes, or `None` for
+    /// [ColumnSize::Auto].
+    fn name(&self) -> Option<&str> {
+        match self {
+            ColumnSize::Auto => None,
+            ColumnSize::IsNarrow => Some("narrow"),
+            ColumnSize::Is0 => Some("0"),
+            ColumnSize::Is1 => Some("1"),
+            ColumnSize::Is2 => Some("2"),
+            ColumnSize::Is3 => Some("3"),
+            ColumnSize::Is4 => Some("4"),
+            ColumnSize::Is5 => Some("5"),
+            ColumnSize::Is6 => Some("6"),
+            ColumnSize::Is7 => Some("7"),
+            ColumnSize::Is8 => Some("8"),
+            ColumnSize::Is9 => Some("9"),
+            ColumnSize::Is10 => Some("10"),
+            ColumnSize::Is11 => Some("11"),
+            ColumnSize::Is12 => Some("12"),
+            ColumnSize::IsFull => Some("full"),
+            ColumnSize::IsFourFifths => Some("four-fifths"),
+            ColumnSize::IsThreeQuarters => Some("three-quarters"),
+            ColumnSize::IsTwoThirds => Some("two-thirds"),
+            ColumnSize::IsThreeFifths => Some("three-fifths"),
+            ColumnSize::IsHalf => Some("half"),
+            ColumnSize::IsTwoFifths => Some("two-fifths"),
+            ColumnSize::IsOneThird => Some("one-third"),
+            ColumnSize::IsOneQuarter => Some("one-quarter"),
+            ColumnSize::IsOneFifth => Some("one-fifth"),
+        }
+    }
+
+    /// The class which sets the size of a column, for example
+    /// `is-half`.
+    pub fn to_class(&self) -> Option<String> {
+        self.name().map(|name| format!("is-{}", name))
+    }
+
+    /// The class which sets the size of a column at the specified
+    /// `breakpoint`, for example `is-half-tablet`.
+    pub fn to_breakpoint_class(&self, breakpoint: Breakpoint) -> Option<String> {
+        self.to_class().map(|class| breakpoint.suffix(&class))
+    }
+
+    /// The class which offsets a column by this size, for example
+    /// `is-offset-half`. There is no offset for [ColumnSize::Auto],
+    /// [ColumnSize::IsNarrow] or [ColumnSize::IsFull].
+    pub fn to_offset_class(&self) -> Option<String> {
+        match self {
+            ColumnSize::IsNarrow | ColumnSize::IsFull => None,
+            _ => self.name().map(|name| format!("is-offset-{}", name)),
+        }
+    }
+
+    /// The class which offsets a column by this size at the
+    /// specified `breakpoint`, for example `is-offset-half-tablet`.
+    pub fn to_offset_breakpoint_class(&self, breakpoint: Breakpoint) -> Option<String> {
+        self.to_offset_class()
+            .map(|class| breakpoint.suffix(&class))
+    }
+}
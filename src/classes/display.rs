@@ -0,0 +1,35 @@
+use super::Breakpoint;
+
+/// A `bulma` display
+/// [helper](https://bulma.io/documentation/helpers/visibility-helpers/#show),
+/// which sets the `display` of an element.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Display {
+    Block,
+    Flex,
+    Inline,
+    InlineBlock,
+    InlineFlex,
+}
+
+impl Display {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Display::Block => "block",
+            Display::Flex => "flex",
+            Display::Inline => "inline",
+            Display::InlineBlock => "inline-block",
+            Display::InlineFlex => "inline-flex",
+        }
+    }
+
+    pub fn to_class(&self) -> String {
+        format!("is-{}", self.to_str())
+    }
+
+    /// The class which applies this display only at the specified
+    /// `breakpoint`, for example `is-flex-mobile`.
+    pub fn to_breakpoint_class(&self, breakpoint: Breakpoint) -> String {
+        breakpoint.suffix(&self.to_class())
+    }
+}
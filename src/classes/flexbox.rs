@@ -0,0 +1,214 @@
+/// The number used by the `is-flex-grow-*` and `is-flex-shrink-*`
+/// helpers, from `0` to `5`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FlexFactor {
+    Is0,
+    Is1,
+    Is2,
+    Is3,
+    Is4,
+    Is5,
+}
+
+impl FlexFactor {
+    fn name(&self) -> &str {
+        match self {
+            FlexFactor::Is0 => "0",
+            FlexFactor::Is1 => "1",
+            FlexFactor::Is2 => "2",
+            FlexFactor::Is3 => "3",
+            FlexFactor::Is4 => "4",
+            FlexFactor::Is5 => "5",
+        }
+    }
+}
+
+/// The `flex-direction` of a flex container.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FlexDirection {
+    Row,
+    RowReverse,
+    Column,
+    ColumnReverse,
+}
+
+impl FlexDirection {
+    fn name(&self) -> &str {
+        match self {
+            FlexDirection::Row => "row",
+            FlexDirection::RowReverse => "row-reverse",
+            FlexDirection::Column => "column",
+            FlexDirection::ColumnReverse => "column-reverse",
+        }
+    }
+}
+
+/// The `flex-wrap` of a flex container.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FlexWrap {
+    Nowrap,
+    Wrap,
+    WrapReverse,
+}
+
+impl FlexWrap {
+    fn name(&self) -> &str {
+        match self {
+            FlexWrap::Nowrap => "nowrap",
+            FlexWrap::Wrap => "wrap",
+            FlexWrap::WrapReverse => "wrap-reverse",
+        }
+    }
+}
+
+/// The `justify-content` of a flex container.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum JustifyContent {
+    FlexStart,
+    FlexEnd,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+    Start,
+    End,
+    Left,
+    Right,
+}
+
+impl JustifyContent {
+    fn name(&self) -> &str {
+        match self {
+            JustifyContent::FlexStart => "flex-start",
+            JustifyContent::FlexEnd => "flex-end",
+            JustifyContent::Center => "center",
+            JustifyContent::SpaceBetween => "space-between",
+            JustifyContent::SpaceAround => "space-around",
+            JustifyContent::SpaceEvenly => "space-evenly",
+            JustifyContent::Start => "start",
+            JustifyContent::End => "end",
+            JustifyContent::Left => "left",
+            JustifyContent::Right => "right",
+        }
+    }
+}
+
+/// The `align-content` of a flex container.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum AlignContent {
+    FlexStart,
+    FlexEnd,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+    Stretch,
+    Start,
+    End,
+    Baseline,
+}
+
+impl AlignContent {
+    fn name(&self) -> &str {
+        match self {
+            AlignContent::FlexStart => "flex-start",
+            AlignContent::FlexEnd => "flex-end",
+            AlignContent::Center => "center",
+            AlignContent::SpaceBetween => "space-between",
+            AlignContent::SpaceAround => "space-around",
+            AlignContent::SpaceEvenly => "space-evenly",
+            AlignContent::Stretch => "stretch",
+            AlignContent::Start => "start",
+            AlignContent::End => "end",
+            AlignContent::Baseline => "baseline",
+        }
+    }
+}
+
+/// The `align-items` of a flex container.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum AlignItems {
+    Stretch,
+    FlexStart,
+    FlexEnd,
+    Center,
+    Baseline,
+    Start,
+    End,
+    SelfStart,
+    SelfEnd,
+}
+
+impl AlignItems {
+    fn name(&self) -> &str {
+        match self {
+            AlignItems::Stretch => "stretch",
+            AlignItems::FlexStart => "flex-start",
+            AlignItems::FlexEnd => "flex-end",
+            AlignItems::Center => "center",
+            AlignItems::Baseline => "baseline",
+            AlignItems::Start => "start",
+            AlignItems::End => "end",
+            AlignItems::SelfStart => "self-start",
+            AlignItems::SelfEnd => "self-end",
+        }
+    }
+}
+
+/// The `align-self` of a flex item.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum AlignSelf {
+    Auto,
+    FlexStart,
+    FlexEnd,
+    Center,
+    Baseline,
+    Stretch,
+}
+
+impl AlignSelf {
+    fn name(&self) -> &str {
+        match self {
+            AlignSelf::Auto => "auto",
+            AlignSelf::FlexStart => "flex-start",
+            AlignSelf::FlexEnd => "flex-end",
+            AlignSelf::Center => "center",
+            AlignSelf::Baseline => "baseline",
+            AlignSelf::Stretch => "stretch",
+        }
+    }
+}
+
+/// A `bulma`
+/// [flexbox helper](https://bulma.io/documentation/helpers/flexbox-helpers/).
+/// The container helpers only take effect on elements with
+/// [Display::Flex](super::Display::Flex) or
+/// [Display::InlineFlex](super::Display::InlineFlex).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Flexbox {
+    Direction(FlexDirection),
+    Wrap(FlexWrap),
+    JustifyContent(JustifyContent),
+    AlignContent(AlignContent),
+    AlignItems(AlignItems),
+    AlignSelf(AlignSelf),
+    Grow(FlexFactor),
+    Shrink(FlexFactor),
+}
+
+impl Flexbox {
+    pub fn to_class(&self) -> String {
+        match self {
+            Flexbox::Direction(direction) => format!("is-flex-direction-{}", direction.name()),
+            Flexbox::Wrap(wrap) => format!("is-flex-wrap-{}", wrap.name()),
+            Flexbox::JustifyContent(justify) => {
+                format!("is-justify-content-{}", justify.name())
+            }
+            Flexbox::AlignContent(align) => format!("is-align-content-{}", align.name()),
+            Flexbox::AlignItems(align) => format!("is-align-items-{}", align.name()),
+            Flexbox::AlignSelf(align) => format!("is-align-self-{}", align.name()),
+            Flexbox::Grow(factor) => format!("is-flex-grow-{}", factor.name()),
+            Flexbox::Shrink(factor) => format!("is-flex-shrink-{}", factor.name()),
+        }
+    }
+}
@@ -1,5 +1,24 @@
+pub mod breakpoint;
+pub mod builder;
 pub mod color;
+pub mod column_size;
+pub mod display;
+pub mod flexbox;
 pub mod size;
+pub mod spacing;
+pub mod typography;
+pub mod visibility;
 
-pub use color::Color;
-pub use size::Size;
+pub use breakpoint::Breakpoint;
+pub use builder::Classes;
+pub use color::{Color, MainColor, Shade};
+pub use column_size::ColumnSize;
+pub use display::Display;
+pub use flexbox::{
+    AlignContent, AlignItems, AlignSelf, FlexDirection, FlexFactor, FlexWrap, Flexbox,
+    JustifyContent,
+};
+pub use size::{ControlSize, GroupSize, IconSize, TitleSize};
+pub use spacing::{Spacing, SpacingLevel, SpacingProperty, SpacingSide};
+pub use typography::{TextAlignment, TextSize, TextTransform, TextWeight};
+pub use visibility::Visibility;
@@ -1,25 +1,132 @@
-#[derive(PartialEq, Clone, Debug)]
-#[allow(dead_code)]
-pub enum Size {
+/// The size of a `bulma` control or component which supports the
+/// `is-small`, `is-medium` and `is-large` modifiers, such as a
+/// button, input, select, tabs or pagination.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ControlSize {
     Small,
+    #[default]
     Normal,
     Medium,
-    Big,
+    Large,
 }
 
-impl Size {
+impl ControlSize {
     pub fn to_class(&self) -> Option<String> {
         match self {
-            Size::Small => Some("is-small".to_string()),
-            Size::Normal => None,
-            Size::Medium => Some("is-medium".to_string()),
-            Size::Big => Some("is-big".to_string()),
+            ControlSize::Small => Some("is-small".to_string()),
+            ControlSize::Normal => None,
+            ControlSize::Medium => Some("is-medium".to_string()),
+            ControlSize::Large => Some("is-large".to_string()),
         }
     }
 }
 
-impl Default for Size {
-    fn default() -> Self {
-        Size::Normal
+/// The size of a `bulma`
+/// [title or subtitle](https://bulma.io/documentation/elements/title/),
+/// from `is-1` (the largest) to `is-6`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TitleSize {
+    Is1,
+    Is2,
+    Is3,
+    Is4,
+    Is5,
+    Is6,
+}
+
+impl TitleSize {
+    /// The number of this size, from `1` (the largest) to `6`.
+    pub fn number(&self) -> u8 {
+        match self {
+            TitleSize::Is1 => 1,
+            TitleSize::Is2 => 2,
+            TitleSize::Is3 => 3,
+            TitleSize::Is4 => 4,
+            TitleSize::Is5 => 5,
+            TitleSize::Is6 => 6,
+        }
+    }
+
+    pub fn to_class(&self) -> String {
+        format!("is-{}", self.number())
+    }
+}
+
+/// The size of a `bulma` [icon](https://bulma.io/documentation/elements/icon/)
+/// container, along with the matching size of a Font Awesome or
+/// Material Design icon within it.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum IconSize {
+    Small,
+    #[default]
+    Normal,
+    Medium,
+    Large,
+}
+
+impl IconSize {
+    /// The class of the `icon` container.
+    pub fn to_class(&self) -> Option<String> {
+        match self {
+            IconSize::Small => Some("is-small".to_string()),
+            IconSize::Normal => None,
+            IconSize::Medium => Some("is-medium".to_string()),
+            IconSize::Large => Some("is-large".to_string()),
+        }
+    }
+
+    /// The class of a Font Awesome icon which fills the `icon`
+    /// container of this size.
+    pub fn font_awesome_class(&self) -> Option<String> {
+        match self {
+            IconSize::Small | IconSize::Normal => None,
+            IconSize::Medium => Some("fa-lg".to_string()),
+            IconSize::Large => Some("fa-2x".to_string()),
+        }
+    }
+
+    /// The class of the largest Material Design icon which fits the
+    /// `icon` container of this size (`1rem`, `1.5rem`, `2rem` and
+    /// `3rem`). `mdi-18px` is the smallest size available, so it is
+    /// also used for [IconSize::Small].
+    pub fn material_design_class(&self) -> String {
+        match self {
+            IconSize::Small => "mdi-18px".to_string(),
+            IconSize::Normal | IconSize::Medium => "mdi-24px".to_string(),
+            IconSize::Large => "mdi-48px".to_string(),
+        }
+    }
+}
+
+/// The size of all the elements in a group of `buttons` or `tags`,
+/// applied to the group with the `are-*` modifiers.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum GroupSize {
+    Small,
+    #[default]
+    Normal,
+    Medium,
+    Large,
+}
+
+impl GroupSize {
+    /// The class of a `buttons` group.
+    pub fn buttons_class(&self) -> Option<String> {
+        match self {
+            GroupSize::Small => Some("are-small".to_string()),
+            GroupSize::Normal => None,
+            GroupSize::Medium => Some("are-medium".to_string()),
+            GroupSize::Large => Some("are-large".to_string()),
+        }
+    }
+
+    /// The class of a `tags` group. Tags have no small size, so
+    /// [GroupSize::Small] is the same as [GroupSize::Normal].
+    pub fn tags_class(&self) -> Option<String> {
+        match self {
+            GroupSize::Small | GroupSize::Normal => None,
+            GroupSize::Medium => Some("are-medium".to_string()),
+            GroupSize::Large => Some("are-large".to_string()),
+        }
     }
 }
@@ -0,0 +1,119 @@
+/// Whether a [Spacing] applies to the margin or the padding of an
+/// element.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SpacingProperty {
+    Margin,
+    Padding,
+}
+
+impl SpacingProperty {
+    fn name(&self) -> &str {
+        match self {
+            SpacingProperty::Margin => "m",
+            SpacingProperty::Padding => "p",
+        }
+    }
+}
+
+/// The sides of an element which a [Spacing] applies to.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum SpacingSide {
+    /// All four sides.
+    #[default]
+    All,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    /// The left and right sides.
+    Horizontal,
+    /// The top and bottom sides.
+    Vertical,
+}
+
+impl SpacingSide {
+    fn name(&self) -> &str {
+        match self {
+            SpacingSide::All => "",
+            SpacingSide::Top => "t",
+            SpacingSide::Right => "r",
+            SpacingSide::Bottom => "b",
+            SpacingSide::Left => "l",
+            SpacingSide::Horizontal => "x",
+            SpacingSide::Vertical => "y",
+        }
+    }
+}
+
+/// The amount of a [Spacing], from `0` to `3rem`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SpacingLevel {
+    /// `0`
+    Is0,
+    /// `0.25rem`
+    Is1,
+    /// `0.5rem`
+    Is2,
+    /// `0.75rem`
+    Is3,
+    /// `1rem`
+    Is4,
+    /// `1.5rem`
+    Is5,
+    /// `3rem`
+    Is6,
+    /// `auto`
+    Auto,
+}
+
+impl SpacingLevel {
+    fn name(&self) -> &str {
+        match self {
+            SpacingLevel::Is0 => "0",
+            SpacingLevel::Is1 => "1",
+            SpacingLevel::Is2 => "2",
+            SpacingLevel::Is3 => "3",
+            SpacingLevel::Is4 => "4",
+            SpacingLevel::Is5 => "5",
+            SpacingLevel::Is6 => "6",
+            SpacingLevel::Auto => "auto",
+        }
+    }
+}
+
+/// A `bulma`
+/// [spacing helper](https://bulma.io/documentation/helpers/spacing-helpers/),
+/// such as `mt-2` or `px-4`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Spacing {
+    pub property: SpacingProperty,
+    pub side: SpacingSide,
+    pub level: SpacingLevel,
+}
+
+impl Spacing {
+    pub fn margin(side: SpacingSide, level: SpacingLevel) -> Self {
+        Self {
+            property: SpacingProperty::Margin,
+            side,
+            level,
+        }
+    }
+
+    pub fn padding(side: SpacingSide, level: SpacingLevel) -> Self {
+        Self {
+            property: SpacingProperty::Padding,
+            side,
+            level,
+        }
+    }
+
+    pub fn to_class(&self) -> String {
+        format!(
+            "{}{}-{}",
+            self.property.name(),
+            self.side.name(),
+            self.level.name()
+        )
+    }
+}
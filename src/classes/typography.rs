@@ -0,0 +1,106 @@
+use super::Breakpoint;
+
+/// The font size of text, from `is-size-1` (`3rem`) to `is-size-7`
+/// (`0.75rem`), see `bulma`'s
+/// [typography helpers](https://bulma.io/documentation/helpers/typography-helpers/).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TextSize {
+    Is1,
+    Is2,
+    Is3,
+    Is4,
+    Is5,
+    Is6,
+    Is7,
+}
+
+impl TextSize {
+    pub fn to_class(&self) -> String {
+        let size = match self {
+            TextSize::Is1 => 1,
+            TextSize::Is2 => 2,
+            TextSize::Is3 => 3,
+            TextSize::Is4 => 4,
+            TextSize::Is5 => 5,
+            TextSize::Is6 => 6,
+            TextSize::Is7 => 7,
+        };
+        format!("is-size-{}", size)
+    }
+
+    /// The class which applies this size only at the specified
+    /// `breakpoint`, for example `is-size-5-mobile`.
+    pub fn to_breakpoint_class(&self, breakpoint: Breakpoint) -> String {
+        breakpoint.suffix(&self.to_class())
+    }
+}
+
+/// The font weight of text.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TextWeight {
+    Light,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+}
+
+impl TextWeight {
+    pub fn to_class(&self) -> String {
+        let weight = match self {
+            TextWeight::Light => "light",
+            TextWeight::Normal => "normal",
+            TextWeight::Medium => "medium",
+            TextWeight::Semibold => "semibold",
+            TextWeight::Bold => "bold",
+        };
+        format!("has-text-weight-{}", weight)
+    }
+}
+
+/// The horizontal alignment of text.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TextAlignment {
+    Left,
+    Centered,
+    Right,
+    Justified,
+}
+
+impl TextAlignment {
+    pub fn to_class(&self) -> String {
+        let alignment = match self {
+            TextAlignment::Left => "left",
+            TextAlignment::Centered => "centered",
+            TextAlignment::Right => "right",
+            TextAlignment::Justified => "justified",
+        };
+        format!("has-text-{}", alignment)
+    }
+
+    /// The class which applies this alignment only at the specified
+    /// `breakpoint`, for example `has-text-centered-mobile`.
+    pub fn to_breakpoint_class(&self, breakpoint: Breakpoint) -> String {
+        breakpoint.suffix(&self.to_class())
+    }
+}
+
+/// A transformation of the case or style of text.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TextTransform {
+    Capitalized,
+    Lowercase,
+    Uppercase,
+    Italic,
+}
+
+impl TextTransform {
+    pub fn to_class(&self) -> String {
+        match self {
+            TextTransform::Capitalized => "is-capitalized".to_string(),
+            TextTransform::Lowercase => "is-lowercase".to_string(),
+            TextTransform::Uppercase => "is-uppercase".to_string(),
+            TextTransform::Italic => "is-italic".to_string(),
+        }
+    }
+}
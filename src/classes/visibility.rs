@@ -0,0 +1,34 @@
+use super::Breakpoint;
+
+/// A `bulma`
+/// [visibility helper](https://bulma.io/documentation/helpers/visibility-helpers/).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Visibility {
+    /// Hide the element (`display: none`).
+    Hidden,
+    /// Hide the element, but keep the space it takes up
+    /// (`visibility: hidden`).
+    Invisible,
+    /// Hide the element visually, but keep it available to screen
+    /// readers.
+    ScreenReaderOnly,
+}
+
+impl Visibility {
+    pub fn to_class(&self) -> String {
+        match self {
+            Visibility::Hidden => "is-hidden".to_string(),
+            Visibility::Invisible => "is-invisible".to_string(),
+            Visibility::ScreenReaderOnly => "is-sr-only".to_string(),
+        }
+    }
+
+    /// The class which applies this visibility only at the specified
+    /// `breakpoint`, or `None` if `bulma` has no such class.
+    pub fn to_breakpoint_class(&self, breakpoint: Breakpoint) -> Option<String> {
+        match self {
+            Visibility::Hidden | Visibility::Invisible => Some(breakpoint.suffix(&self.to_class())),
+            Visibility::ScreenReaderOnly => None,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [box](https://bulma.io/documentation/elements/box/).
+
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// `Box` component, a white box which displays its children.
+#[derive(Debug)]
+pub struct Box {
+    props: Props,
+}
+
+/// [Properties](yew::Component::Properties) for [Box].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The content of the box.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) Extra classes to apply to the `box`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Box {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["box".to_string()];
+        classes.extend(self.props.classes.clone());
+
+        html! {
+            <div class=classes>
+                { self.props.children.clone() }
+            </div>
+        }
+    }
+}
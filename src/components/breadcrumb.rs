@@ -0,0 +1,295 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [breadcrumb](https://bulma.io/documentation/components/breadcrumb/).
+//!
+//! With the `router` cargo feature enabled, a [BreadcrumbItem] can be
+//! created from a `yew-router` route with [BreadcrumbItem::route],
+//! and clicking it changes the route without reloading the page. For
+//! apps which use hash-based routing, set the breadcrumb's
+//! [Props::route_prefix] to `"#"`.
+
+use crate::{classes::ControlSize, components::icon};
+
+use web_sys::MouseEvent;
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+#[cfg(feature = "router")]
+use yew_router::{
+    agent::{RouteAgentDispatcher, RouteRequest},
+    route::Route,
+    Switch,
+};
+use yewtil::NeqAssign;
+
+/// An item (a link to a page) in a [Breadcrumb].
+#[derive(PartialEq, Clone, Debug)]
+pub struct BreadcrumbItem {
+    /// The text of the link.
+    pub label: String,
+    /// (Optional) The `href` of the link.
+    pub href: Option<String>,
+    /// (Optional) An icon to display before the label.
+    pub icon_props: Option<icon::Props>,
+    /// The `yew-router` route which clicking the link changes to,
+    /// rather than loading the `href`.
+    #[cfg(feature = "router")]
+    route: Option<String>,
+}
+
+impl BreadcrumbItem {
+    /// Create a new `BreadcrumbItem` without a link or an icon.
+    pub fn new<L: Into<String>>(label: L) -> Self {
+        Self {
+            label: label.into(),
+            href: None,
+            icon_props: None,
+            #[cfg(feature = "router")]
+            route: None,
+        }
+    }
+
+    /// Create a new `BreadcrumbItem` which links to the page of a
+    /// `yew-router` `route`. Clicking the link changes the route
+    /// without reloading the page, unless it is a modified click
+    /// (such as ctrl + click to open the page in a new tab).
+    #[cfg(feature = "router")]
+    pub fn route<L: Into<String>, SW: Switch>(label: L, route: SW) -> Self {
+        let route: Route = Route::from(route);
+        Self {
+            route: Some(route.route),
+            ..Self::new(label)
+        }
+    }
+
+    /// Whether clicking the link changes the route of the `yew-router`
+    /// router.
+    #[cfg(feature = "router")]
+    fn is_route(&self) -> bool {
+        self.route.is_some()
+    }
+
+    #[cfg(not(feature = "router"))]
+    fn is_route(&self) -> bool {
+        false
+    }
+
+    /// The `href` attribute of the link. For an item created with
+    /// [BreadcrumbItem::route] this is the route, prefixed with
+    /// `route_prefix` unless the route is already a fragment.
+    #[cfg(feature = "router")]
+    fn link_href(&self, route_prefix: &str) -> Option<String> {
+        match &self.route {
+            Some(route) if route.starts_with('#') => Some(route.clone()),
+            Some(route) => Some(format!("{}{}", route_prefix, route)),
+            None => self.href.clone(),
+        }
+    }
+
+    #[cfg(not(feature = "router"))]
+    fn link_href(&self, _route_prefix: &str) -> Option<String> {
+        self.href.clone()
+    }
+
+    /// Factory method to set the `href` of the link.
+    pub fn href<H: Into<String>>(mut self, href: H) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Factory method to set the icon displayed before the label.
+    pub fn icon(mut self, icon_props: icon::Props) -> Self {
+        self.icon_props = Some(icon_props);
+        self
+    }
+}
+
+/// The separator displayed between the items of a [Breadcrumb].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum BreadcrumbSeparator {
+    /// `/`
+    #[default]
+    Slash,
+    /// `→`
+    Arrow,
+    /// `•`
+    Bullet,
+    /// `·`
+    Dot,
+    /// `≻`
+    Succeeds,
+}
+
+impl BreadcrumbSeparator {
+    pub fn to_class(&self) -> Option<String> {
+        match self {
+            BreadcrumbSeparator::Slash => None,
+            BreadcrumbSeparator::Arrow => Some("has-arrow-separator".to_string()),
+            BreadcrumbSeparator::Bullet => Some("has-bullet-separator".to_string()),
+            BreadcrumbSeparator::Dot => Some("has-dot-separator".to_string()),
+            BreadcrumbSeparator::Succeeds => Some("has-succeeds-separator".to_string()),
+        }
+    }
+}
+
+/// The horizontal alignment of a [Breadcrumb].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum BreadcrumbAlignment {
+    #[default]
+    Left,
+    Centered,
+    Right,
+}
+
+impl BreadcrumbAlignment {
+    pub fn to_class(&self) -> Option<String> {
+        match self {
+            BreadcrumbAlignment::Left => None,
+            BreadcrumbAlignment::Centered => Some("is-centered".to_string()),
+            BreadcrumbAlignment::Right => Some("is-right".to_string()),
+        }
+    }
+}
+
+/// `Breadcrumb` component. The last item is displayed as the current
+/// page.
+#[derive(Debug)]
+pub struct Breadcrumb {
+    props: Props,
+    link: ComponentLink<Self>,
+    #[cfg(feature = "router")]
+    router: RouteAgentDispatcher,
+}
+
+pub enum Msg {
+    /// Change the route to the route of the item with the specified
+    /// index.
+    Navigate(usize),
+}
+
+/// [Properties](yew::Component::Properties) for [Breadcrumb].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The items of the breadcrumb, from the top level page to the
+    /// current page.
+    pub items: Vec<BreadcrumbItem>,
+    /// (Optional) The separator displayed between the items.
+    #[prop_or_default]
+    pub separator: BreadcrumbSeparator,
+    /// (Optional) The alignment of the breadcrumb.
+    #[prop_or_default]
+    pub alignment: BreadcrumbAlignment,
+    /// (Optional) The size of the breadcrumb.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) The `aria-label` of the breadcrumb. By default this
+    /// is `"breadcrumbs"`.
+    #[prop_or("breadcrumbs".to_string())]
+    pub aria_label: String,
+    /// (Optional) A prefix for the `href` of the items created with
+    /// [BreadcrumbItem::route] (with the `router` feature), so that
+    /// the links can be opened in a new tab. Set this to `"#"` if the
+    /// app uses hash-based routing. By default this is empty.
+    #[prop_or_default]
+    pub route_prefix: String,
+    /// (Optional) Extra classes to apply to the `breadcrumb`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Breadcrumb {
+    #[cfg(feature = "router")]
+    fn navigate(&mut self, index: usize) {
+        if let Some(route) = self
+            .props
+            .items
+            .get(index)
+            .and_then(|item| item.route.as_ref())
+        {
+            self.router
+                .send(RouteRequest::ChangeRoute(Route::new_no_state(route)));
+        }
+    }
+
+    #[cfg(not(feature = "router"))]
+    fn navigate(&mut self, _index: usize) {}
+
+    fn view_item(&self, index: usize, item: &BreadcrumbItem) -> Html {
+        let current = index + 1 == self.props.items.len();
+
+        // Only plain left clicks change the route, so that the
+        // browser still handles clicks which open the link in a new
+        // tab or window.
+        let is_route = item.is_route();
+        let onclick = self.link.batch_callback(move |event: MouseEvent| {
+            let modified =
+                event.ctrl_key() || event.meta_key() || event.shift_key() || event.alt_key();
+            (is_route && event.button() == 0 && !modified).then(|| {
+                event.prevent_default();
+                Msg::Navigate(index)
+            })
+        });
+
+        let icon = match &item.icon_props {
+            Some(icon_props) => html! { <icon::Icon with icon_props.clone()/> },
+            None => html! {},
+        };
+
+        html! {
+            <li class=current.then_some("is-active")>
+                <a
+                    href=item.link_href(&self.props.route_prefix)
+                    aria-current=current.then_some("page")
+                    onclick=onclick>
+                    { icon }
+                    <span>{ &item.label }</span>
+                </a>
+            </li>
+        }
+    }
+}
+
+impl Component for Breadcrumb {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            props,
+            link,
+            #[cfg(feature = "router")]
+            router: RouteAgentDispatcher::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Navigate(index) => {
+                self.navigate(index);
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["breadcrumb".to_string()];
+        classes.extend(self.props.separator.to_class());
+        classes.extend(self.props.alignment.to_class());
+        classes.extend(self.props.size.to_class());
+        classes.extend(self.props.classes.clone());
+
+        html! {
+            <nav class=classes aria-label=self.props.aria_label.clone()>
+                <ul>
+                    {
+                        for self.props.items
+                            .iter()
+                            .enumerate()
+                            .map(|(index, item)| self.view_item(index, item))
+                    }
+                </ul>
+            </nav>
+        }
+    }
+}
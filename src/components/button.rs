@@ -0,0 +1,160 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [button](https://bulma.io/documentation/elements/button/).
+
+use crate::{
+    classes::{ControlSize, MainColor},
+    components::icon,
+};
+use web_sys::MouseEvent;
+use yew::{html, Callback, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// The `type` attribute of a `<button>` element.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ButtonType {
+    /// A button which does nothing by default.
+    #[default]
+    Button,
+    /// A button which submits the form that it belongs to.
+    Submit,
+    /// A button which resets the form that it belongs to.
+    Reset,
+}
+
+impl ButtonType {
+    pub fn to_str(&self) -> &str {
+        match self {
+            ButtonType::Button => "button",
+            ButtonType::Submit => "submit",
+            ButtonType::Reset => "reset",
+        }
+    }
+}
+
+/// `Button` component.
+#[derive(Debug)]
+pub struct Button {
+    props: Props,
+}
+
+/// [Properties](yew::Component::Properties) for [Button].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The content of the button, typically its text.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The color of the button.
+    #[prop_or_default]
+    pub color: Option<MainColor>,
+    /// (Optional) The size of the button.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) Apply the `is-outlined` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_outlined: bool,
+    /// (Optional) Apply the `is-light` style, a lighter version of
+    /// `color`. Has no effect unless `color` has a light style, see
+    /// [MainColor::light_class]. By default this is `false`.
+    #[prop_or(false)]
+    pub is_light: bool,
+    /// (Optional) Apply the `is-inverted` style, which swaps the
+    /// button's background and text colors. This is intended for
+    /// buttons displayed on a background of the same `color`, such
+    /// as within a `hero`. By default this is `false`.
+    #[prop_or(false)]
+    pub is_inverted: bool,
+    /// (Optional) Apply the `is-loading` style, which replaces the
+    /// content of the button with a spinner. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_loading: bool,
+    /// (Optional) Apply the `is-fullwidth` style, so the button
+    /// takes the full width of its container. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_fullwidth: bool,
+    /// (Optional) Disable the button. By default this is `false`.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// (Optional) An icon to display before the content of the
+    /// button.
+    #[prop_or_default]
+    pub icon_props: Option<icon::Props>,
+    /// (Optional) The `type` attribute of the `<button>`. By default
+    /// this is [ButtonType::Button].
+    #[prop_or_default]
+    pub button_type: ButtonType,
+    /// (Optional) Extra classes to apply to the `<button>`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+    /// (Optional) Callback for when the button is clicked.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+}
+
+impl Component for Button {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["button".to_string()];
+
+        if let Some(color) = &self.props.color {
+            classes.push(color.class());
+            if self.props.is_light {
+                classes.extend(color.light_class());
+            }
+        }
+
+        classes.extend(self.props.size.to_class());
+
+        let flags = [
+            (self.props.is_outlined, "is-outlined"),
+            (self.props.is_inverted, "is-inverted"),
+            (self.props.is_loading, "is-loading"),
+            (self.props.is_fullwidth, "is-fullwidth"),
+        ];
+
+        classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+
+        classes.extend(self.props.classes.clone());
+
+        let content = match &self.props.icon_props {
+            Some(icon_props) => html! {
+                <>
+                    <icon::Icon with icon_props.clone()/>
+                    <span>{ self.props.children.clone() }</span>
+                </>
+            },
+            None => html! { <>{ self.props.children.clone() }</> },
+        };
+
+        html! {
+            <button
+                class=classes
+                type=self.props.button_type.to_str().to_string()
+                disabled=self.props.disabled
+                onclick=self.props.onclick.clone()>
+                { content }
+            </button>
+        }
+    }
+}
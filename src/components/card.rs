@@ -0,0 +1,110 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [card](https://bulma.io/documentation/components/card/).
+
+use crate::components::icon;
+
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// `Card` component, which displays its children within the
+/// `card-content`.
+#[derive(Debug)]
+pub struct Card {
+    props: Props,
+}
+
+/// [Properties](yew::Component::Properties) for [Card].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The content of the `card-content`.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The title displayed in the `card-header`. The
+    /// header is not displayed if this and `header_icon` are `None`.
+    #[prop_or_default]
+    pub header_title: Option<String>,
+    /// (Optional) An icon displayed on the right of the
+    /// `card-header`.
+    #[prop_or_default]
+    pub header_icon: Option<icon::Props>,
+    /// (Optional) The content of the `card-image`, typically a
+    /// `figure` containing an `img`.
+    #[prop_or_default]
+    pub image: Option<Html>,
+    /// (Optional) The content of the `card-footer`, which should
+    /// consist of elements with the `card-footer-item` class. The
+    /// footer is not displayed if this is `None`.
+    #[prop_or_default]
+    pub footer: Option<Html>,
+    /// (Optional) Extra classes to apply to the `card`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Card {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["card".to_string()];
+        classes.extend(self.props.classes.clone());
+
+        let header = if self.props.header_title.is_some() || self.props.header_icon.is_some() {
+            let title = match &self.props.header_title {
+                Some(title) => html! { <p class="card-header-title">{ title }</p> },
+                None => html! {},
+            };
+
+            let icon = match &self.props.header_icon {
+                Some(icon_props) => html! {
+                    <span class="card-header-icon">
+                        <icon::Icon with icon_props.clone()/>
+                    </span>
+                },
+                None => html! {},
+            };
+
+            html! {
+                <header class="card-header">
+                    { title }
+                    { icon }
+                </header>
+            }
+        } else {
+            html! {}
+        };
+
+        let image = match &self.props.image {
+            Some(image) => html! { <div class="card-image">{ image.clone() }</div> },
+            None => html! {},
+        };
+
+        let footer = match &self.props.footer {
+            Some(footer) => html! { <footer class="card-footer">{ footer.clone() }</footer> },
+            None => html! {},
+        };
+
+        html! {
+            <div class=classes>
+                { header }
+                { image }
+                <div class="card-content">
+                    { self.props.children.clone() }
+                </div>
+                { footer }
+            </div>
+        }
+    }
+}
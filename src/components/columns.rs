@@ -0,0 +1,196 @@
+//! `yew` [Component](yew::Component)s to render `bulma`
+//! [columns](https://bulma.io/documentation/columns/).
+
+use crate::classes::{Breakpoint, ColumnSize};
+
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// The breakpoint from which [Columns] are displayed side by side,
+/// rather than stacked vertically.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ColumnsBreakpoint {
+    /// `is-mobile`, the columns are displayed side by side on all
+    /// screen sizes.
+    Mobile,
+    /// `is-desktop`, the columns are only displayed side by side from
+    /// the desktop breakpoint.
+    Desktop,
+}
+
+impl ColumnsBreakpoint {
+    pub fn to_class(&self) -> String {
+        match self {
+            ColumnsBreakpoint::Mobile => "is-mobile".to_string(),
+            ColumnsBreakpoint::Desktop => "is-desktop".to_string(),
+        }
+    }
+}
+
+/// `Columns` component, a container for [Column]s.
+#[derive(Debug)]
+pub struct Columns {
+    props: ColumnsProps,
+}
+
+/// [Properties](yew::Component::Properties) for [Columns].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct ColumnsProps {
+    /// The columns, typically [Column]s.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) Apply the `is-gapless` style, which removes the gap
+    /// between the columns. By default this is `false`.
+    #[prop_or(false)]
+    pub is_gapless: bool,
+    /// (Optional) Apply the `is-multiline` style, which wraps the
+    /// columns onto multiple lines. By default this is `false`.
+    #[prop_or(false)]
+    pub is_multiline: bool,
+    /// (Optional) Apply the `is-vcentered` style, which centers the
+    /// columns vertically. By default this is `false`.
+    #[prop_or(false)]
+    pub is_vcentered: bool,
+    /// (Optional) Apply the `is-centered` style, which centers the
+    /// columns horizontally. By default this is `false`.
+    #[prop_or(false)]
+    pub is_centered: bool,
+    /// (Optional) The breakpoint from which the columns are
+    /// displayed side by side. By default the columns are displayed
+    /// side by side from the tablet breakpoint.
+    #[prop_or_default]
+    pub breakpoint: Option<ColumnsBreakpoint>,
+    /// (Optional) Extra classes to apply to the `columns`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Columns {
+    type Message = ();
+    type Properties = ColumnsProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["columns".to_string()];
+
+        let flags = [
+            (self.props.is_gapless, "is-gapless"),
+            (self.props.is_multiline, "is-multiline"),
+            (self.props.is_vcentered, "is-vcentered"),
+            (self.props.is_centered, "is-centered"),
+        ];
+
+        classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+
+        classes.extend(
+            self.props
+                .breakpoint
+                .map(|breakpoint| breakpoint.to_class()),
+        );
+
+        classes.extend(self.props.classes.clone());
+
+        html! {
+            <div class=classes>
+                { self.props.children.clone() }
+            </div>
+        }
+    }
+}
+
+/// `Column` component, a column within [Columns].
+#[derive(Debug)]
+pub struct Column {
+    props: ColumnProps,
+}
+
+/// [Properties](yew::Component::Properties) for [Column].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct ColumnProps {
+    /// The content of the column.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The size of the column. By default this is
+    /// [ColumnSize::Auto].
+    #[prop_or_default]
+    pub size: ColumnSize,
+    /// (Optional) Override the size of the column at specific
+    /// breakpoints.
+    #[prop_or_default]
+    pub breakpoint_sizes: Vec<(Breakpoint, ColumnSize)>,
+    /// (Optional) Offset the column by the specified size.
+    #[prop_or_default]
+    pub offset: Option<ColumnSize>,
+    /// (Optional) Override the offset of the column at specific
+    /// breakpoints.
+    #[prop_or_default]
+    pub breakpoint_offsets: Vec<(Breakpoint, ColumnSize)>,
+    /// (Optional) Extra classes to apply to the `column`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Column {
+    type Message = ();
+    type Properties = ColumnProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["column".to_string()];
+
+        classes.extend(self.props.size.to_class());
+        classes.extend(
+            self.props
+                .breakpoint_sizes
+                .iter()
+                .filter_map(|(breakpoint, size)| size.to_breakpoint_class(*breakpoint)),
+        );
+
+        classes.extend(
+            self.props
+                .offset
+                .and_then(|offset| offset.to_offset_class()),
+        );
+        classes.extend(
+            self.props
+                .breakpoint_offsets
+                .iter()
+                .filter_map(|(breakpoint, offset)| offset.to_offset_breakpoint_class(*breakpoint)),
+        );
+
+        classes.extend(self.props.classes.clone());
+
+        html! {
+            <div class=classes>
+                { self.props.children.clone() }
+            </div>
+        }
+    }
+}
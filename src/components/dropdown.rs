@@ -0,0 +1,487 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [dropdown](https://bulma.io/documentation/components/dropdown/).
+
+use crate::{
+    classes::ControlSize,
+    components::{form::focus::focus_node, icon, select::SelectOption},
+};
+
+use uuid::Uuid;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, KeyboardEvent, MouseEvent, Node};
+use yew::{
+    html, utils::document, Callback, Component, ComponentLink, Html, NodeRef, Properties,
+    ShouldRender,
+};
+
+/// An entry in the menu of a [Dropdown].
+#[derive(PartialEq, Clone, Debug)]
+pub enum DropdownItem<T> {
+    /// An item which can be chosen.
+    Option(SelectOption<T>),
+    /// A `dropdown-divider` between items.
+    Divider,
+    /// A heading for the items which follow it, such as the label of
+    /// a group of options. It can't be chosen.
+    Heading(String),
+}
+
+impl<T> DropdownItem<T> {
+    /// The option for this item, if it is an item which can be
+    /// chosen.
+    pub fn option(&self) -> Option<&SelectOption<T>> {
+        match self {
+            DropdownItem::Option(option) => Some(option),
+            DropdownItem::Divider | DropdownItem::Heading(_) => None,
+        }
+    }
+
+    /// Whether this item can currently be chosen.
+    fn is_choosable(&self) -> bool {
+        self.option()
+            .map(|option| !option.disabled)
+            .unwrap_or(false)
+    }
+}
+
+impl<T> From<SelectOption<T>> for DropdownItem<T> {
+    fn from(option: SelectOption<T>) -> Self {
+        DropdownItem::Option(option)
+    }
+}
+
+/// A `click` listener on the document which emits `callback` when
+/// the user clicks outside of the element referenced by `root`. The
+/// listener is removed from the document when this is dropped.
+///
+/// This is used rather than the trigger button's `blur` event, which
+/// browsers such as Safari don't fire because clicking a button
+/// doesn't focus it.
+#[derive(Debug)]
+struct OutsideClickListener {
+    closure: Closure<dyn Fn(Event)>,
+}
+
+impl OutsideClickListener {
+    fn new(root: NodeRef, callback: Callback<()>) -> Self {
+        let closure = Closure::wrap(Box::new(move |event: Event| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let inside = match (root.get(), target) {
+                (Some(root), Some(target)) => root.contains(Some(&target)),
+                _ => false,
+            };
+            if !inside {
+                callback.emit(());
+            }
+        }) as Box<dyn Fn(Event)>);
+
+        document()
+            .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
+            .expect("unable to add dropdown click listener to the document");
+
+        Self { closure }
+    }
+}
+
+impl Drop for OutsideClickListener {
+    fn drop(&mut self) {
+        let _ = document()
+            .remove_event_listener_with_callback("click", self.closure.as_ref().unchecked_ref());
+    }
+}
+
+/// `Dropdown` component.
+///
+/// The menu is opened by clicking on the trigger button, or by
+/// pressing `ArrowDown`, `Enter` or `Space` while it has focus. While
+/// the menu is open the items can be highlighted with the arrow
+/// keys, chosen with `Enter`, and the menu closed with `Escape` or
+/// `Tab`. The menu also closes when the user clicks anywhere outside
+/// of the dropdown.
+#[derive(Debug)]
+pub struct Dropdown<T: PartialEq + Clone + 'static> {
+    /// Whether the menu is open.
+    active: bool,
+    /// Closes the menu when the user clicks outside of the dropdown,
+    /// registered while the menu is open.
+    outside_click_listener: Option<OutsideClickListener>,
+    /// A reference to the `dropdown` element.
+    root_ref: NodeRef,
+    /// Index into [Props::items] of the item which is currently
+    /// highlighted using the keyboard.
+    highlighted: Option<usize>,
+    /// Whether the trigger button should receive focus once rendered.
+    focus_pending: bool,
+    id: String,
+    props: Props<T>,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    /// Open the menu if it is closed, or close it if it is open.
+    Toggle,
+    /// Close the menu.
+    Close,
+    /// Choose the item with the specified index.
+    Choose(usize),
+    KeyDown(KeyboardEvent),
+}
+
+/// [Properties](yew::Component::Properties) for [Dropdown].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props<T: Clone> {
+    /// The items in the menu.
+    pub items: Vec<DropdownItem<T>>,
+    /// (Optional) The selected value, the item with this value is
+    /// displayed with the `is-active` style.
+    #[prop_or_default]
+    pub selected: Option<T>,
+    /// (Optional) Callback for when an item is chosen.
+    #[prop_or_default]
+    pub onchange: Callback<T>,
+    /// (Optional) The text of the trigger button. If this is `None`,
+    /// the label of the selected item is displayed, or the
+    /// `placeholder` if there is no selected item.
+    #[prop_or_default]
+    pub label: Option<String>,
+    /// (Optional) The text of the trigger button when there is no
+    /// `label` and no selected item. By default this is `"↪"`.
+    #[prop_or("↪".to_string())]
+    pub placeholder: String,
+    /// (Optional) An icon to display after the text of the trigger
+    /// button, such as an arrow.
+    #[prop_or_default]
+    pub icon_props: Option<icon::Props>,
+    /// (Optional) The size of the trigger button.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) Disable the trigger button. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// (Optional) Apply the `is-hoverable` style, which opens the
+    /// menu when the mouse hovers over the dropdown. By default this
+    /// is `false`.
+    #[prop_or(false)]
+    pub is_hoverable: bool,
+    /// (Optional) Apply the `is-right` style, which aligns the menu
+    /// to the right of the trigger. By default this is `false`.
+    #[prop_or(false)]
+    pub is_right: bool,
+    /// (Optional) Apply the `is-up` style, which opens the menu above
+    /// the trigger. By default this is `false`.
+    #[prop_or(false)]
+    pub is_up: bool,
+    /// (Optional) Extra classes to apply to the `dropdown` element.
+    #[prop_or_default]
+    pub div_classes: Vec<String>,
+    /// (Optional) Extra classes to apply to the trigger button.
+    #[prop_or_default]
+    pub button_classes: Vec<String>,
+    /// (Optional) A reference to the trigger button.
+    #[prop_or_default]
+    pub trigger_ref: NodeRef,
+    /// (Optional) The `id` of the trigger button.
+    #[prop_or_default]
+    pub id: Option<String>,
+    /// (Optional) Sets `aria-invalid="true"` on the trigger button.
+    /// By default this is `false`.
+    #[prop_or(false)]
+    pub aria_invalid: bool,
+    /// (Optional) Sets `aria-required="true"` on the trigger button.
+    /// By default this is `false`.
+    #[prop_or(false)]
+    pub aria_required: bool,
+    /// (Optional) The `aria-describedby` attribute of the trigger
+    /// button.
+    #[prop_or_default]
+    pub aria_describedby: Option<String>,
+}
+
+impl<T> Dropdown<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    fn menu_id(&self) -> String {
+        format!("{}-menu", self.id)
+    }
+
+    fn item_id(&self, index: usize) -> String {
+        format!("{}-item-{}", self.id, index)
+    }
+
+    /// The index of the item with the selected value.
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.props.selected.as_ref()?;
+        self.props.items.iter().position(|item| {
+            item.option()
+                .map(|option| &option.value == selected)
+                .unwrap_or(false)
+        })
+    }
+
+    /// The index of the next choosable item after `from` in the
+    /// specified direction, wrapping around the ends of the menu.
+    fn next_choosable(&self, from: Option<usize>, forwards: bool) -> Option<usize> {
+        let len = self.props.items.len();
+        (1..=len)
+            .map(|offset| match (from, forwards) {
+                (Some(from), true) => (from + offset) % len,
+                (Some(from), false) => (from + len - offset) % len,
+                (None, true) => offset - 1,
+                (None, false) => len - offset,
+            })
+            .find(|index| self.props.items[*index].is_choosable())
+    }
+
+    fn open(&mut self) {
+        self.active = true;
+        self.outside_click_listener = Some(OutsideClickListener::new(
+            self.root_ref.clone(),
+            self.link.callback(|_| Msg::Close),
+        ));
+        self.highlighted = self
+            .selected_index()
+            .or_else(|| self.next_choosable(None, true));
+        self.focus_pending = true;
+    }
+
+    fn close(&mut self) {
+        self.active = false;
+        self.outside_click_listener = None;
+        self.highlighted = None;
+    }
+
+    fn label(&self) -> String {
+        if let Some(label) = &self.props.label {
+            return label.clone();
+        }
+
+        self.selected_index()
+            .and_then(|index| self.props.items[index].option())
+            .map(|option| option.label.clone())
+            .unwrap_or_else(|| self.props.placeholder.clone())
+    }
+
+    fn view_item(&self, index: usize, item: &DropdownItem<T>) -> Html {
+        let option = match item {
+            DropdownItem::Option(option) => option,
+            DropdownItem::Divider => return html! { <hr class="dropdown-divider"/> },
+            DropdownItem::Heading(heading) => {
+                return html! {
+                    <div class="dropdown-item has-text-weight-semibold" role="presentation">
+                        { heading }
+                    </div>
+                }
+            }
+        };
+
+        let selected = self.props.selected.as_ref() == Some(&option.value);
+        let mut classes = vec!["dropdown-item".to_string()];
+
+        if selected || self.highlighted == Some(index) {
+            classes.push("is-active".to_string());
+        }
+
+        // `onmousedown` is used instead of `onclick`, and the default
+        // action prevented, so that the trigger button keeps the focus
+        // when an item is chosen.
+        let onmousedown = if option.disabled {
+            Callback::from(|event: MouseEvent| event.prevent_default())
+        } else {
+            self.link.callback(move |event: MouseEvent| {
+                event.prevent_default();
+                Msg::Choose(index)
+            })
+        };
+
+        html! {
+            <a
+                class=classes
+                id=self.item_id(index)
+                role="option"
+                aria-selected=selected.to_string()
+                aria-disabled=option.disabled.then_some("true")
+                onmousedown=onmousedown>
+                { &option.label }
+            </a>
+        }
+    }
+}
+
+impl<T> Component for Dropdown<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    type Message = Msg;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            active: false,
+            outside_click_listener: None,
+            root_ref: NodeRef::default(),
+            highlighted: None,
+            focus_pending: false,
+            id: props
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("dropdown-{}", Uuid::new_v4())),
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Toggle => {
+                if self.active {
+                    self.close();
+                } else if !self.props.disabled {
+                    self.open();
+                }
+                true
+            }
+            Msg::Close => {
+                if self.active {
+                    self.close();
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::Choose(index) => {
+                let option = self.props.items.get(index).and_then(DropdownItem::option);
+                if let Some(option) = option.filter(|option| !option.disabled) {
+                    self.props.onchange.emit(option.value.clone());
+                }
+                self.close();
+                true
+            }
+            Msg::KeyDown(event) => match (self.active, event.key().as_str()) {
+                (false, "ArrowDown") | (false, "Enter") | (false, " ") => {
+                    event.prevent_default();
+                    self.update(Msg::Toggle)
+                }
+                (true, "ArrowDown") | (true, "ArrowUp") => {
+                    event.prevent_default();
+                    let forwards = event.key() == "ArrowDown";
+                    self.highlighted = self.next_choosable(self.highlighted, forwards);
+                    true
+                }
+                (true, "Enter") | (true, " ") => {
+                    event.prevent_default();
+                    match self.highlighted {
+                        Some(index) => self.update(Msg::Choose(index)),
+                        None => self.update(Msg::Close),
+                    }
+                }
+                (true, "Escape") => {
+                    event.prevent_default();
+                    self.update(Msg::Close)
+                }
+                (true, "Tab") => self.update(Msg::Close),
+                _ => false,
+            },
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        if let Some(id) = &props.id {
+            self.id = id.clone();
+        }
+
+        if self.props.items != props.items {
+            self.highlighted = None;
+        }
+
+        if props.disabled {
+            self.close();
+        }
+
+        self.props = props;
+        true
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.focus_pending {
+            self.focus_pending = false;
+            focus_node(&self.props.trigger_ref);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let mut div_classes = vec!["dropdown".to_string()];
+
+        let flags = [
+            (self.active, "is-active"),
+            (self.props.is_hoverable, "is-hoverable"),
+            (self.props.is_right, "is-right"),
+            (self.props.is_up, "is-up"),
+        ];
+
+        div_classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+        div_classes.extend(self.props.div_classes.clone());
+
+        let mut button_classes = vec!["button".to_string()];
+        button_classes.extend(self.props.size.to_class());
+        button_classes.extend(self.props.button_classes.clone());
+
+        let icon = match &self.props.icon_props {
+            Some(icon_props) => html! { <icon::Icon with icon_props.clone()/> },
+            None => html! {},
+        };
+
+        let activedescendant = if self.active {
+            self.highlighted.map(|index| self.item_id(index))
+        } else {
+            None
+        };
+
+        html! {
+            <div class=div_classes ref=self.root_ref.clone()>
+                <div class="dropdown-trigger">
+                    <button
+                        type="button"
+                        class=button_classes
+                        ref=self.props.trigger_ref.clone()
+                        id=self.props.id.clone()
+                        disabled=self.props.disabled
+                        aria-haspopup="listbox"
+                        aria-expanded=self.active.to_string()
+                        aria-controls=self.menu_id()
+                        aria-activedescendant=activedescendant
+                        aria-invalid=self.props.aria_invalid.then_some("true")
+                        aria-required=self.props.aria_required.then_some("true")
+                        aria-describedby=self.props.aria_describedby.clone()
+                        onclick=self.link.callback(|_| Msg::Toggle)
+                        onkeydown=self.link.callback(Msg::KeyDown)>
+                        <span>{ self.label() }</span>
+                        { icon }
+                    </button>
+                </div>
+                <div class="dropdown-menu" id=self.menu_id() role="listbox">
+                    <div class="dropdown-content">
+                        {
+                            for self.props.items
+                                .iter()
+                                .enumerate()
+                                .map(|(index, item)| self.view_item(index, item))
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use super::{
-    FieldKey, FieldLink, FieldMsg, FieldProps, FormField, FormFieldLink, FormMsg,
-    NeqAssignFieldProps,
+    focus::focus_node,
+    help::{errors_view, help_view, success_help_view},
+    FieldIds, FieldKey, FieldLink, FieldMsg, FieldProps, FormField, FormFieldLink, FormMsg,
+    NeqAssignFieldProps, SharedErrorRenderer,
 };
 use form_validation::{AsyncValidatable, AsyncValidator, ValidationErrors};
 use std::{fmt::Debug, future::Future, pin::Pin, rc::Rc};
-use yew::{html, Callback, Children, Component, ComponentLink, Properties};
+use yew::{html, Callback, Children, Component, ComponentLink, NodeRef, Properties};
 use yewtil::future::LinkFuture;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -56,11 +58,13 @@ where
     }
 }
 
-impl<Key> Into<CheckboxFieldMsg<Key>> for FieldMsg {
-    fn into(self) -> CheckboxFieldMsg<Key> {
-        match self {
+impl<Key> From<FieldMsg> for CheckboxFieldMsg<Key> {
+    fn from(msg: FieldMsg) -> Self {
+        match msg {
             FieldMsg::Validate => CheckboxFieldMsg::Validate,
             FieldMsg::ClearValidationErrors => CheckboxFieldMsg::ClearValidationErrors,
+            FieldMsg::Focus => CheckboxFieldMsg::Focus,
+            FieldMsg::Rerender => CheckboxFieldMsg::Rerender,
         }
     }
 }
@@ -104,6 +108,30 @@ where
     /// are not reported to the `Form`.
     #[prop_or_default]
     pub extra_errors: ValidationErrors<Key>,
+    /// (Optional) Override the [ErrorRenderer](super::ErrorRenderer)
+    /// provided by the `Form` for rendering this field's validation
+    /// errors.
+    #[prop_or_default]
+    pub error_renderer: Option<SharedErrorRenderer<Key>>,
+    /// (Optional) Whether this field is required, which is
+    /// announced to assistive technologies with the `aria-required`
+    /// attribute. This does not perform any validation, see
+    /// `validator`. By default this is `false`.
+    #[prop_or(false)]
+    pub required: bool,
+    /// (Optional) Help text to display beneath the field.
+    #[prop_or_default]
+    pub help: Option<String>,
+    /// (Optional) Whether to apply the `is-success` style to this
+    /// field once a validation has completed without any errors. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub show_success: bool,
+    /// (Optional) Help text to display beneath the field when
+    /// `show_success` is `true`, and a validation has completed
+    /// without any errors.
+    #[prop_or_default]
+    pub success_help: Option<String>,
 }
 
 impl<Key> FieldProps<Key> for CheckboxFieldProps<Key>
@@ -119,6 +147,9 @@ where
     fn extra_errors(&self) -> &ValidationErrors<Key> {
         &self.extra_errors
     }
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &self.error_renderer
+    }
 }
 
 pub enum CheckboxFieldMsg<Key> {
@@ -126,6 +157,11 @@ pub enum CheckboxFieldMsg<Key> {
     Validate,
     ValidationErrors(ValidationErrors<Key>),
     ClearValidationErrors,
+    /// Move the browser's focus to the checkbox `<input>` element.
+    Focus,
+    /// Re-render the field, because the [Form](super::Form)'s
+    /// shared configuration (such as its error renderer) has changed.
+    Rerender,
 }
 
 pub struct CheckboxField<Key>
@@ -138,6 +174,27 @@ where
     link: ComponentLink<Self>,
     validation_errors: ValidationErrors<Key>,
     display_validation_errors: ValidationErrors<Key>,
+    /// Whether a validation has completed since the value was last
+    /// updated, or the validation errors were last cleared.
+    validated: bool,
+    ids: FieldIds,
+    input_ref: NodeRef,
+}
+
+impl<Key> CheckboxField<Key>
+where
+    Key: FieldKey + 'static,
+{
+    /// Whether this field should currently be displayed as having
+    /// been successfully validated.
+    fn success(&self) -> bool {
+        self.props.show_success
+            && self.validated
+            && self
+                .display_validation_errors
+                .get(&self.props.field_key)
+                .is_none()
+    }
 }
 
 impl<Key> Component for CheckboxField<Key>
@@ -163,6 +220,9 @@ where
             link,
             validation_errors: ValidationErrors::default(),
             display_validation_errors: props.extra_errors.clone(),
+            validated: false,
+            ids: FieldIds::new(),
+            input_ref: NodeRef::default(),
             props,
         }
     }
@@ -171,6 +231,7 @@ where
         match msg {
             CheckboxFieldMsg::Update => {
                 self.value = self.value.toggle();
+                self.validated = false;
                 self.props.onupdate.emit(self.value);
                 self.form_link
                     .send_form_message(FormMsg::FieldValueUpdate(self.props.field_key.clone()));
@@ -192,6 +253,7 @@ where
             }
             CheckboxFieldMsg::ValidationErrors(errors) => {
                 self.validation_errors = errors.clone();
+                self.validated = true;
 
                 let mut display_errors = errors;
                 display_errors.extend(self.props.extra_errors.clone());
@@ -206,6 +268,7 @@ where
             }
             CheckboxFieldMsg::ClearValidationErrors => {
                 self.validation_errors = ValidationErrors::default();
+                self.validated = false;
                 self.display_validation_errors = self.props.extra_errors.clone();
 
                 self.form_link
@@ -215,6 +278,11 @@ where
                     ));
                 true
             }
+            CheckboxFieldMsg::Focus => {
+                focus_node(&self.input_ref);
+                false
+            }
+            CheckboxFieldMsg::Rerender => true,
         }
     }
 
@@ -235,27 +303,42 @@ where
     fn view(&self) -> yew::Html {
         let onchange = self.link.callback(|_| CheckboxFieldMsg::Update);
 
-        let validation_error =
-            if let Some(errors) = self.display_validation_errors.get(&self.props.field_key) {
-                let error_message = errors.to_string();
-                html! {<p class="help is-danger">{ error_message }</p>}
-            } else {
-                html! {}
-            };
+        let errors = self.display_validation_errors.get(&self.props.field_key);
+        let has_errors = errors.is_some();
+
+        let validation_error = if let Some(errors) = errors {
+            errors_view(&self.ids, self.props.render_errors(&errors))
+        } else {
+            html! {}
+        };
+
+        let success = self.success();
+        let described_by = self.ids.described_by(
+            has_errors,
+            success && self.props.success_help.is_some(),
+            self.props.help.is_some(),
+        );
 
         html! {
             <div class="field">
                 <div class="control">
                     <label class="checkbox">
                         <input
+                            ref=self.input_ref.clone()
+                            id=self.ids.control()
                             type="checkbox"
                             onchange=onchange
                             checked=self.value.checked()
+                            aria-invalid=has_errors.then_some("true")
+                            aria-required=self.props.required.then_some("true")
+                            aria-describedby=described_by
                             />
                         { self.props.children.clone() }
                     </label>
                 </div>
                 { validation_error }
+                { success_help_view(&self.ids, success, &self.props.success_help) }
+                { help_view(&self.ids, &self.props.help) }
             </div>
         }
     }
@@ -0,0 +1,127 @@
+//! Configurable rendering of the validation errors displayed beneath
+//! form fields.
+
+use form_validation::ValidationError;
+use std::{fmt::Debug, rc::Rc};
+use yew::{html, Html};
+
+/// Renders the validation errors for a single form field.
+///
+/// A default renderer for all the fields in a form can be set with
+/// [FormProps::error_renderer](super::FormProps::error_renderer),
+/// and this can be overriden for individual fields using their
+/// `error_renderer` property.
+///
+/// This trait is implemented for closures with the signature
+/// `Fn(&[ValidationError<Key>]) -> Html`.
+pub trait ErrorRenderer<Key> {
+    /// Render the validation `errors` for a field. This is only
+    /// called when there is at least one error to display.
+    fn render(&self, errors: &[ValidationError<Key>]) -> Html;
+}
+
+impl<Key, F> ErrorRenderer<Key> for F
+where
+    F: Fn(&[ValidationError<Key>]) -> Html,
+{
+    fn render(&self, errors: &[ValidationError<Key>]) -> Html {
+        (self)(errors)
+    }
+}
+
+/// A cloneable, reference counted [ErrorRenderer], for use in
+/// component properties. Two instances are equal if they point to
+/// the same renderer.
+pub struct SharedErrorRenderer<Key> {
+    renderer: Rc<dyn ErrorRenderer<Key>>,
+}
+
+impl<Key> SharedErrorRenderer<Key> {
+    /// Create a new `SharedErrorRenderer`.
+    pub fn new<R>(renderer: R) -> Self
+    where
+        R: ErrorRenderer<Key> + 'static,
+    {
+        Self {
+            renderer: Rc::new(renderer),
+        }
+    }
+}
+
+impl<Key> ErrorRenderer<Key> for SharedErrorRenderer<Key> {
+    fn render(&self, errors: &[ValidationError<Key>]) -> Html {
+        self.renderer.render(errors)
+    }
+}
+
+impl<Key> Clone for SharedErrorRenderer<Key> {
+    fn clone(&self) -> Self {
+        Self {
+            renderer: Rc::clone(&self.renderer),
+        }
+    }
+}
+
+impl<Key> PartialEq for SharedErrorRenderer<Key> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.renderer, &other.renderer)
+    }
+}
+
+impl<Key> Debug for SharedErrorRenderer<Key> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedErrorRenderer({:p})", self.renderer)
+    }
+}
+
+impl<Key> Default for SharedErrorRenderer<Key>
+where
+    Key: 'static,
+{
+    fn default() -> Self {
+        Self::new(JoinedErrorsRenderer)
+    }
+}
+
+/// Renders all the errors joined into a single `<p class="help
+/// is-danger">` separated by commas. This is the default
+/// [ErrorRenderer].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JoinedErrorsRenderer;
+
+impl<Key> ErrorRenderer<Key> for JoinedErrorsRenderer {
+    fn render(&self, errors: &[ValidationError<Key>]) -> Html {
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        html! {<p class="help is-danger">{ messages.join(", ") }</p>}
+    }
+}
+
+/// Renders only the first error in a `<p class="help is-danger">`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FirstErrorRenderer;
+
+impl<Key> ErrorRenderer<Key> for FirstErrorRenderer {
+    fn render(&self, errors: &[ValidationError<Key>]) -> Html {
+        match errors.first() {
+            Some(error) => html! {<p class="help is-danger">{ error.to_string() }</p>},
+            None => html! {},
+        }
+    }
+}
+
+/// Renders each error as an item in a bulleted list, within a
+/// `<div class="help is-danger">`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorListRenderer;
+
+impl<Key> ErrorRenderer<Key> for ErrorListRenderer {
+    fn render(&self, errors: &[ValidationError<Key>]) -> Html {
+        html! {
+            <div class="help is-danger">
+                <ul>
+                    { for errors.iter().map(|error| html! {<li>{ error.to_string() }</li>}) }
+                </ul>
+            </div>
+        }
+    }
+}
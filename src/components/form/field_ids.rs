@@ -0,0 +1,75 @@
+use uuid::Uuid;
+
+/// Unique ids for the elements that make up a form field. These are
+/// generated once when the field is created, and remain stable for
+/// its lifetime. They are used to associate labels, help text and
+/// validation errors with the field's control for accessibility.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldIds {
+    id: String,
+}
+
+impl FieldIds {
+    /// Generate a new set of unique ids.
+    pub fn new() -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// The id of the field's control (`<input>`, `<select>`, etc).
+    pub fn control(&self) -> String {
+        self.id.clone()
+    }
+
+    /// The id of one of the field's controls, for fields containing
+    /// multiple controls such as radio buttons.
+    pub fn option(&self, index: usize) -> String {
+        format!("{}-option-{}", self.id, index)
+    }
+
+    /// The id of the element containing the field's validation
+    /// errors.
+    pub fn errors(&self) -> String {
+        format!("{}-errors", self.id)
+    }
+
+    /// The id of the field's help text.
+    pub fn help(&self) -> String {
+        format!("{}-help", self.id)
+    }
+
+    /// The id of the field's success help text.
+    pub fn success_help(&self) -> String {
+        format!("{}-success-help", self.id)
+    }
+
+    /// The value for the `aria-describedby` attribute of the field's
+    /// control, referencing the elements which are currently being
+    /// displayed. Returns `None` if none of them are.
+    pub fn described_by(&self, errors: bool, success_help: bool, help: bool) -> Option<String> {
+        let mut ids = Vec::new();
+
+        if errors {
+            ids.push(self.errors());
+        }
+        if success_help {
+            ids.push(self.success_help());
+        }
+        if help {
+            ids.push(self.help());
+        }
+
+        if ids.is_empty() {
+            None
+        } else {
+            Some(ids.join(" "))
+        }
+    }
+}
+
+impl Default for FieldIds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,20 +1,34 @@
-use super::{FieldKey, FieldLink, FormFieldLink};
+use super::{ErrorRenderer, FieldKey, FieldLink, FormFieldLink, SharedErrorRenderer};
 use form_validation::ValidationErrors;
 use std::rc::Rc;
-use yew::{Properties, ShouldRender};
+use yew::{Html, Properties, ShouldRender};
 
 pub trait FieldProps<Key>
 where
-    Key: FieldKey,
+    Key: FieldKey + 'static,
 {
     fn form_link(&self) -> &FormFieldLink<Key>;
     fn field_key(&self) -> &Key;
     fn extra_errors(&self) -> &ValidationErrors<Key>;
+    /// If present, this overrides the default [ErrorRenderer]
+    /// provided by the `form_link`. By default this is `None`.
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &None
+    }
+
+    /// Render the validation `errors` for this field using its
+    /// [ErrorRenderer].
+    fn render_errors(&self, errors: &ValidationErrors<Key>) -> Html {
+        match self.error_renderer() {
+            Some(error_renderer) => error_renderer.render(&errors.errors),
+            None => self.form_link().error_renderer().render(&errors.errors),
+        }
+    }
 }
 
 pub trait NeqAssignFieldProps<Key>: FieldProps<Key> + Properties
 where
-    Key: FieldKey,
+    Key: FieldKey + 'static,
 {
     fn neq_assign_field<C>(&mut self, new: Self, create_field_link: C) -> ShouldRender
     where
@@ -0,0 +1,17 @@
+use web_sys::HtmlElement;
+use yew::NodeRef;
+
+/// Move the browser's focus to the element referenced by `node_ref`,
+/// if it is currently rendered.
+pub fn focus_node(node_ref: &NodeRef) {
+    if let Some(element) = node_ref.cast::<HtmlElement>() {
+        focus_element(&element);
+    }
+}
+
+/// Move the browser's focus to the `element`.
+pub fn focus_element(element: &HtmlElement) {
+    if let Err(error) = element.focus() {
+        log::error!("Unable to focus element: {:?}", error);
+    }
+}
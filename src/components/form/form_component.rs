@@ -1,4 +1,4 @@
-use super::{FieldKey, FieldMsg, FormFieldLink};
+use super::{FieldKey, FieldMsg, FormFieldLink, FormState, SharedErrorRenderer};
 
 use form_validation::ValidationErrors;
 use std::collections::HashMap;
@@ -30,17 +30,64 @@ where
     }
 
     pub fn all_validated(&self) -> bool {
-        let mut all_validated = true;
         let field_keys = self.props.form_link.registered_fields();
+        all_validated(&self.validation_errors, &field_keys)
+    }
 
-        for key in &field_keys {
-            all_validated &= self.validation_errors.contains_key(key)
+    /// The current state of the form, which is shared with the
+    /// [FormFieldLink].
+    fn state(&self) -> FormState {
+        FormState {
+            validating: self.validating,
+            submitting: self.props.submitting,
         }
+    }
+
+    /// Set whether the form is waiting for its fields to complete
+    /// their validations, and share it with the [FormFieldLink].
+    fn set_validating(&mut self, validating: bool) {
+        self.validating = validating;
+        self.form_link.set_state(self.state());
+    }
 
-        all_validated
+    /// Move the browser's focus to the first field (in registration
+    /// order) which currently has validation errors.
+    fn focus_first_error(&self) {
+        let first_error_key = self
+            .props
+            .form_link
+            .registered_fields()
+            .into_iter()
+            .find(|key| {
+                self.validation_errors
+                    .get(key)
+                    .map(|errors| !errors.is_empty())
+                    .unwrap_or(false)
+            });
+
+        if let Some(key) = first_error_key {
+            self.props
+                .form_link
+                .send_field_message(&key, FieldMsg::Focus);
+        }
     }
 }
 
+/// Whether every field in `field_keys` has reported the result of a
+/// validation in `validation_errors`.
+pub(super) fn all_validated<Key: FieldKey>(
+    validation_errors: &HashMap<Key, ValidationErrors<Key>>,
+    field_keys: &[Key],
+) -> bool {
+    let mut all_validated = true;
+
+    for key in field_keys {
+        all_validated &= validation_errors.contains_key(key)
+    }
+
+    all_validated
+}
+
 #[derive(Clone)]
 pub enum FormMsg<Key> {
     FieldValueUpdate(Key),
@@ -80,6 +127,23 @@ where
     /// internal `<form>`'s submit action is invoked.
     #[prop_or(true)]
     pub form_onsubmit: bool,
+    /// The default renderer for the validation errors of fields in
+    /// this form, which can be overriden by the fields themselves. By
+    /// default this is [JoinedErrorsRenderer](super::JoinedErrorsRenderer).
+    #[prop_or_default]
+    pub error_renderer: SharedErrorRenderer<Key>,
+    /// Whether to move the browser's focus to the first field with
+    /// validation errors when the form is submitted and `onsubmit`
+    /// is triggered with an `Err`. By default this is `false`.
+    #[prop_or(false)]
+    pub focus_first_error: bool,
+    /// (Optional) Whether the form is currently being submitted, for
+    /// example while the data from `onsubmit` is being sent to a
+    /// server. This is shared with components such as
+    /// [SubmitButton](super::SubmitButton) through the form's
+    /// [FormState]. By default this is `false`.
+    #[prop_or(false)]
+    pub submitting: bool,
 }
 
 impl<Key> Component for Form<Key>
@@ -92,6 +156,11 @@ where
     fn create(props: FormProps<Key>, link: ComponentLink<Self>) -> Self {
         let field_link = props.form_link.clone();
         field_link.register_form(link.clone());
+        field_link.set_error_renderer(props.error_renderer.clone());
+        field_link.set_state(FormState {
+            validating: false,
+            submitting: props.submitting,
+        });
 
         Form {
             validation_errors: HashMap::new(),
@@ -110,7 +179,7 @@ where
 
                 // Clear the errors to ensure that we re-validate all the fields.
                 self.validation_errors.clear();
-                self.validating = true;
+                self.set_validating(true);
 
                 self.props
                     .form_link
@@ -123,6 +192,9 @@ where
                 let result = if validation_errors.is_empty() {
                     Ok(())
                 } else {
+                    if self.props.focus_first_error {
+                        self.focus_first_error();
+                    }
                     Err(validation_errors)
                 };
                 self.props.onsubmit.emit(result);
@@ -134,7 +206,7 @@ where
                 self.props.onvalidateupdate.emit(self.validation_errors());
 
                 if self.validating && self.all_validated() {
-                    self.validating = false;
+                    self.set_validating(false);
                     self.link.send_message(FormMsg::Submit)
                 }
                 true
@@ -173,7 +245,11 @@ where
                 self.form_link = field_link;
             }
 
+            self.form_link
+                .set_error_renderer(props.error_renderer.clone());
+
             self.props = props;
+            self.form_link.set_state(self.state());
             true
         } else {
             false
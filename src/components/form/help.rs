@@ -0,0 +1,31 @@
+//! Help text which is displayed beneath form fields.
+
+use super::FieldIds;
+use yew::{html, Html};
+
+/// Render the static help text for a field, or nothing if there is
+/// no help text.
+pub fn help_view(ids: &FieldIds, help: &Option<String>) -> Html {
+    match help {
+        Some(help) => html! {<p class="help" id=ids.help()>{ help }</p>},
+        None => html! {},
+    }
+}
+
+/// Render the success help text for a field if `success` is `true`
+/// and there is success help text to display.
+pub fn success_help_view(ids: &FieldIds, success: bool, success_help: &Option<String>) -> Html {
+    match success_help {
+        Some(success_help) if success => {
+            html! {<p class="help is-success" id=ids.success_help()>{ success_help }</p>}
+        }
+        _ => html! {},
+    }
+}
+
+/// Render the `errors` for a field (produced by its
+/// [ErrorRenderer](super::ErrorRenderer)) inside an element which can
+/// be referenced by the field's `aria-describedby` attribute.
+pub fn errors_view(ids: &FieldIds, errors: Html) -> Html {
+    html! {<div id=ids.errors()>{ errors }</div>}
+}
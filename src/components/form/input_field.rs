@@ -2,11 +2,17 @@ use crate::components::form::{FieldKey, FormMsg};
 
 use form_validation::{AsyncValidatable, AsyncValidator, ValidationErrors};
 use yew::{
-    html, Callback, ChangeData, Component, ComponentLink, Html, InputData, Properties, ShouldRender,
+    html, Callback, ChangeData, Component, ComponentLink, Html, InputData, NodeRef, Properties,
+    ShouldRender,
 };
 use yewtil::future::LinkFuture;
 
-use super::{FieldLink, FieldMsg, FieldProps, FormField, FormFieldLink, NeqAssignFieldProps};
+use super::{
+    focus::focus_node,
+    help::{errors_view, help_view, success_help_view},
+    FieldIds, FieldLink, FieldMsg, FieldProps, FormField, FormFieldLink, NeqAssignFieldProps,
+    SharedErrorRenderer,
+};
 
 use std::{
     fmt::{Debug, Display},
@@ -71,6 +77,11 @@ where
     value: Type::Value,
     validation_errors: ValidationErrors<Key>,
     display_validation_errors: ValidationErrors<Key>,
+    /// Whether a validation has completed since the value was last
+    /// updated, or the validation errors were last cleared.
+    validated: bool,
+    ids: FieldIds,
+    input_ref: NodeRef,
     props: InputFieldProps<Key, Type::Value>,
     form_link: FormFieldLink<Key>,
     link: ComponentLink<Self>,
@@ -88,6 +99,17 @@ where
             Label::None => None,
         }
     }
+
+    /// Whether this field should currently be displayed with the
+    /// `is-success` style.
+    fn success(&self) -> bool {
+        self.props.show_success
+            && self.validated
+            && self
+                .display_validation_errors
+                .get(&self.props.field_key)
+                .is_none()
+    }
 }
 
 pub enum InputFieldMsg<Key, Value> {
@@ -98,6 +120,11 @@ pub enum InputFieldMsg<Key, Value> {
     Validate,
     SetValidationErrors(ValidationErrors<Key>),
     ClearValidationErrors,
+    /// Move the browser's focus to the `<input>` element.
+    Focus,
+    /// Re-render the field, because the [Form](super::Form)'s
+    /// shared configuration (such as its error renderer) has changed.
+    Rerender,
 }
 
 pub struct InputFieldLink<Key, Type>
@@ -119,11 +146,13 @@ where
     }
 }
 
-impl<Type, Key> Into<InputFieldMsg<Type, Key>> for FieldMsg {
-    fn into(self) -> InputFieldMsg<Type, Key> {
-        match self {
+impl<Key, Value> From<FieldMsg> for InputFieldMsg<Key, Value> {
+    fn from(msg: FieldMsg) -> Self {
+        match msg {
             FieldMsg::Validate => InputFieldMsg::Validate,
             FieldMsg::ClearValidationErrors => InputFieldMsg::ClearValidationErrors,
+            FieldMsg::Focus => InputFieldMsg::Focus,
+            FieldMsg::Rerender => InputFieldMsg::Rerender,
         }
     }
 }
@@ -189,15 +218,17 @@ pub enum ValidateOn {
     None,
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Default)]
 pub enum LabelStyle {
+    #[default]
     Above,
 }
 
 /// How to produce the label text.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Default)]
 pub enum Label {
     /// Use `field_key`'s `Display` implementation for the label text.
+    #[default]
     FieldKey,
     /// Use the supplied string for the label text.
     Text(String),
@@ -205,35 +236,18 @@ pub enum Label {
     None,
 }
 
-impl Default for Label {
-    fn default() -> Self {
-        Self::FieldKey
-    }
-}
-
-impl Default for LabelStyle {
-    fn default() -> Self {
-        Self::Above
-    }
-}
-
 /// How to produce the placeholder text.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum Placeholder {
     /// Use `field_key`'s `Display` implementation for the placeholder text.
     FieldKey,
     /// Use the supplied string for the placeholder text.
     Text(String),
     /// Display no placeholder text.
+    #[default]
     None,
 }
 
-impl Default for Placeholder {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 /// [Properties](yew::Component::Properties) for [InputField].
 #[derive(PartialEq, Clone, Properties, Debug)]
 pub struct InputFieldProps<Key, Value>
@@ -294,6 +308,11 @@ where
     /// are not reported to the `Form`.
     #[prop_or_default]
     pub extra_errors: ValidationErrors<Key>,
+    /// (Optional) Override the [ErrorRenderer](super::ErrorRenderer)
+    /// provided by the `Form` for rendering this field's validation
+    /// errors.
+    #[prop_or_default]
+    pub error_renderer: Option<SharedErrorRenderer<Key>>,
     /// (Optional) Classes to be applied to the `<input>` element. By
     /// default this is `["input"]`.
     #[prop_or(vec!["input".to_string()])]
@@ -302,6 +321,25 @@ where
     /// a rounded shape. By default this is `false`.
     #[prop_or(false)]
     pub is_rounded: bool,
+    /// (Optional) Whether this field is required, which is
+    /// announced to assistive technologies with the `aria-required`
+    /// attribute. This does not perform any validation, see
+    /// `validator`. By default this is `false`.
+    #[prop_or(false)]
+    pub required: bool,
+    /// (Optional) Help text to display beneath the field.
+    #[prop_or_default]
+    pub help: Option<String>,
+    /// (Optional) Whether to apply the `is-success` style to this
+    /// field once a validation has completed without any errors. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub show_success: bool,
+    /// (Optional) Help text to display beneath the field when
+    /// `show_success` is `true`, and a validation has completed
+    /// without any errors.
+    #[prop_or_default]
+    pub success_help: Option<String>,
 }
 
 impl<Key, Value> FieldProps<Key> for InputFieldProps<Key, Value>
@@ -318,6 +356,9 @@ where
     fn extra_errors(&self) -> &ValidationErrors<Key> {
         &self.extra_errors
     }
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &self.error_renderer
+    }
 }
 
 impl<Key, Type> Component for InputField<Key, Type>
@@ -342,6 +383,9 @@ where
             value: Type::default_value(),
             validation_errors: ValidationErrors::default(),
             display_validation_errors: props.extra_errors.clone(),
+            validated: false,
+            ids: FieldIds::new(),
+            input_ref: NodeRef::default(),
             props,
             form_link,
             link,
@@ -355,6 +399,7 @@ where
 
                 if changed {
                     self.value = value.clone();
+                    self.validated = false;
                     self.props.onupdate.emit(value);
 
                     self.form_link
@@ -386,6 +431,7 @@ where
             }
             InputFieldMsg::SetValidationErrors(errors) => {
                 self.validation_errors = errors.clone();
+                self.validated = true;
 
                 let mut display_errors = errors;
                 display_errors.extend(self.props.extra_errors.clone());
@@ -400,6 +446,7 @@ where
             }
             InputFieldMsg::ClearValidationErrors => {
                 self.validation_errors = ValidationErrors::default();
+                self.validated = false;
                 self.display_validation_errors = self.props.extra_errors.clone();
 
                 self.form_link
@@ -409,6 +456,11 @@ where
                     ));
                 true
             }
+            InputFieldMsg::Focus => {
+                focus_node(&self.input_ref);
+                false
+            }
+            InputFieldMsg::Rerender => true,
         }
     }
 
@@ -436,14 +488,26 @@ where
             classes.push("is-rounded".to_string())
         }
 
-        let validation_error =
-            if let Some(errors) = self.display_validation_errors.get(&self.props.field_key) {
-                classes.push("is-danger".to_string());
-                let error_message = errors.to_string();
-                html! {<p class="help is-danger">{ error_message }</p>}
-            } else {
-                html! {}
-            };
+        let errors = self.display_validation_errors.get(&self.props.field_key);
+        let has_errors = errors.is_some();
+
+        let validation_error = if let Some(errors) = errors {
+            classes.push("is-danger".to_string());
+            errors_view(&self.ids, self.props.render_errors(&errors))
+        } else {
+            html! {}
+        };
+
+        let success = self.success();
+        let described_by = self.ids.described_by(
+            has_errors,
+            success && self.props.success_help.is_some(),
+            self.props.help.is_some(),
+        );
+
+        if success {
+            classes.push("is-success".to_string());
+        }
 
         let input_oninput = match self.props.update_on {
             UpdateOn::ChangeEvent => Callback::default(),
@@ -477,7 +541,7 @@ where
                     match label {
                         Some(label) => {
                             html!{
-                                <label class="label">{ label }</label>
+                                <label class="label" for=self.ids.control()>{ label }</label>
                             }
                         },
                         None => {
@@ -488,15 +552,22 @@ where
 
                 <div class="control">
                     <input
+                        ref=self.input_ref.clone()
+                        id=self.ids.control()
                         class=classes
                         value=self.value.to_string()
                         type=Type::input_type()
                         placeholder=placeholder
                         oninput=input_oninput
                         onchange=input_onchange
-                        disabled=self.props.disabled/>
+                        disabled=self.props.disabled
+                        aria-invalid=has_errors.then_some("true")
+                        aria-required=self.props.required.then_some("true")
+                        aria-describedby=described_by/>
                 </div>
                 { validation_error }
+                { success_help_view(&self.ids, success, &self.props.success_help) }
+                { help_view(&self.ids, &self.props.help) }
             </div>
         }
     }
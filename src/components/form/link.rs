@@ -1,4 +1,4 @@
-use super::{Form, FormMsg};
+use super::{Form, FormMsg, SharedErrorRenderer};
 use form_validation::ValidationErrors;
 use std::{
     cell::RefCell,
@@ -7,10 +7,12 @@ use std::{
     hash::Hash,
     rc::Rc,
 };
-use yew::ComponentLink;
+use uuid::Uuid;
+use yew::{Callback, ComponentLink};
 
 type FormLink<Key> = ComponentLink<Form<Key>>;
 type FieldLinkMap<Key> = HashMap<Key, Rc<dyn FieldLink<Key>>>;
+type StateListenerMap = HashMap<Uuid, Callback<FormState>>;
 
 pub trait FieldKey: Clone + PartialEq + Display + Hash + Eq + Debug {}
 
@@ -32,6 +34,24 @@ pub enum FieldMsg {
     /// to the [FormFieldLink] upon completion.
     Validate,
     ClearValidationErrors,
+    /// Move the browser's focus to the field's input element.
+    Focus,
+    /// Re-render the field, because the [Form]'s shared
+    /// configuration (such as its error renderer) has changed.
+    Rerender,
+}
+
+/// The state of a [Form], which can be observed by components such as
+/// [SubmitButton](super::SubmitButton) using
+/// [FormFieldLink::register_state_listener].
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct FormState {
+    /// Whether the form is waiting for all its fields to complete
+    /// their validations before submitting.
+    pub validating: bool,
+    /// Whether the form is being submitted, see
+    /// [FormProps::submitting](super::FormProps::submitting).
+    pub submitting: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -41,6 +61,12 @@ where
 {
     form_link: Rc<RefCell<Option<FormLink<Key>>>>,
     field_links: Rc<RefCell<FieldLinkMap<Key>>>,
+    /// The keys of the registered fields, in the order that they
+    /// were first registered.
+    field_order: Rc<RefCell<Vec<Key>>>,
+    error_renderer: Rc<RefCell<SharedErrorRenderer<Key>>>,
+    state: Rc<RefCell<FormState>>,
+    state_listeners: Rc<RefCell<StateListenerMap>>,
 }
 
 impl<Key> PartialEq for FormFieldLink<Key>
@@ -61,17 +87,77 @@ where
         Self {
             form_link: Rc::new(RefCell::new(None)),
             field_links: Rc::new(RefCell::new(HashMap::new())),
+            field_order: Rc::new(RefCell::new(Vec::new())),
+            error_renderer: Rc::new(RefCell::new(SharedErrorRenderer::default())),
+            state: Rc::new(RefCell::new(FormState::default())),
+            state_listeners: Rc::new(RefCell::new(HashMap::new())),
         }
     }
 
+    /// The keys of the fields registered with this link, in the
+    /// order that they were first registered (which is typically the
+    /// order that they appear in the form).
     pub fn registered_fields(&self) -> Vec<Key> {
-        self.field_links.borrow().keys().cloned().collect()
+        self.field_order.borrow().clone()
     }
 
     pub fn register_form(&self, link: ComponentLink<Form<Key>>) {
         *self.form_link.borrow_mut() = Some(link);
     }
 
+    /// Set the default [ErrorRenderer](super::ErrorRenderer) for
+    /// fields using this link. This is set by the [Form] from
+    /// [FormProps::error_renderer](super::FormProps::error_renderer).
+    /// Fields which are already registered are re-rendered if the
+    /// renderer has changed.
+    pub fn set_error_renderer(&self, error_renderer: SharedErrorRenderer<Key>) {
+        if self.error_renderer.replace(error_renderer.clone()) != error_renderer {
+            self.send_all_fields_message(FieldMsg::Rerender);
+        }
+    }
+
+    /// The default [ErrorRenderer](super::ErrorRenderer) for fields
+    /// using this link.
+    pub fn error_renderer(&self) -> SharedErrorRenderer<Key> {
+        self.error_renderer.borrow().clone()
+    }
+
+    /// The current state of the [Form] using this link.
+    pub fn state(&self) -> FormState {
+        *self.state.borrow()
+    }
+
+    /// Set the current state of the form, and notify the listeners
+    /// registered with [FormFieldLink::register_state_listener] if
+    /// it has changed. This is called by the [Form].
+    pub fn set_state(&self, state: FormState) {
+        if self.state.replace(state) != state {
+            // Release the borrow before notifying, as a listener may
+            // (un)register listeners in response.
+            let listeners: Vec<Callback<FormState>> =
+                self.state_listeners.borrow().values().cloned().collect();
+            for listener in listeners {
+                listener.emit(state);
+            }
+        }
+    }
+
+    /// Register a `callback` which is triggered whenever the state of
+    /// the form changes. Returns an id which can be used to
+    /// unregister the listener with
+    /// [FormFieldLink::unregister_state_listener].
+    pub fn register_state_listener(&self, callback: Callback<FormState>) -> Uuid {
+        let id = Uuid::new_v4();
+        self.state_listeners.borrow_mut().insert(id, callback);
+        id
+    }
+
+    /// Unregister a listener which was registered with
+    /// [FormFieldLink::register_state_listener].
+    pub fn unregister_state_listener(&self, id: &Uuid) {
+        self.state_listeners.borrow_mut().remove(id);
+    }
+
     pub fn form_is_registered(&self) -> bool {
         self.form_link.borrow().is_some()
     }
@@ -81,9 +167,15 @@ where
     }
 
     pub fn register_field(&self, link: Rc<dyn FieldLink<Key>>) {
-        self.field_links
+        let key = link.field_key().clone();
+        if self
+            .field_links
             .borrow_mut()
-            .insert(link.field_key().clone(), link);
+            .insert(key.clone(), link)
+            .is_none()
+        {
+            self.field_order.borrow_mut().push(key);
+        }
     }
 
     pub fn send_field_message(&self, key: &Key, msg: FieldMsg) {
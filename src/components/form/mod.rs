@@ -3,14 +3,26 @@
 //! controls](https://bulma.io/documentation/form/general/).
 
 pub mod checkbox_field;
+mod error_renderer;
+mod field_ids;
 mod field_props;
+pub(crate) mod focus;
 mod form_component;
+mod help;
 pub mod input_field;
 mod link;
 pub mod multi_value_field;
 pub mod radio_field;
 pub mod select_field;
+mod submit_button;
+pub mod tags_input_field;
+pub mod typeahead_field;
 
+pub use error_renderer::{
+    ErrorListRenderer, ErrorRenderer, FirstErrorRenderer, JoinedErrorsRenderer, SharedErrorRenderer,
+};
+pub use field_ids::FieldIds;
 pub use field_props::{FieldProps, NeqAssignFieldProps};
 pub use form_component::{Form, FormMsg, FormProps};
-pub use link::{FieldKey, FieldLink, FieldMsg, FormField, FormFieldLink};
+pub use link::{FieldKey, FieldLink, FieldMsg, FormField, FormFieldLink, FormState};
+pub use submit_button::{SubmitButton, SubmitButtonProps};
@@ -1,25 +1,91 @@
 // TODO: reduce code duplication with select_field and share multi_value module.
 
 use crate::components::form::{
-    FieldKey, FieldLink, FieldMsg, FormField, FormFieldLink, FormMsg, NeqAssignFieldProps,
+    FieldIds, FieldKey, FieldLink, FieldMsg, FormField, FormFieldLink, FormMsg, NeqAssignFieldProps,
 };
 
-use form_validation::{AsyncValidatable, AsyncValidator, ValidationErrors};
-use yew::{Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+use form_validation::{AsyncValidatable, AsyncValidator, ValidationError, ValidationErrors};
+use yew::{Callback, Component, ComponentLink, Html, NodeRef, Properties, ShouldRender};
 
-use super::FieldProps;
-use std::{
-    fmt::{Debug, Display},
-    future::Future,
-    pin::Pin,
-    rc::Rc,
-};
+use super::{focus::focus_node, FieldProps};
+use crate::components::select::SelectOption;
+use std::{fmt::Debug, future::Future, pin::Pin, rc::Rc};
 use yewtil::future::LinkFuture;
 
+/// The type id of the [ValidationError] which is displayed on a
+/// field when its [OptionsLoader] fails.
+pub const OPTIONS_LOAD_ERROR: &str = "OPTIONS_LOAD";
+
+type OptionsFuture<Value> = Pin<Box<dyn Future<Output = Result<Vec<SelectOption<Value>>, String>>>>;
+
+/// An asynchronous function which loads the options for a
+/// [MultiValueField] (for example from a server). If loading fails,
+/// the error message is displayed as an error on the field, and
+/// reported to the [Form](super::Form) as one of the field's
+/// validation errors.
+///
+/// Each `OptionsLoader` has a `key` which identifies the options it
+/// loads. The options are loaded when the field is created, and
+/// reloaded whenever the field is given an `OptionsLoader` with a
+/// different `key`. This means the loader can be created inline in
+/// the parent's `view()`. To reload the options when another field
+/// changes (for example the regions of a selected country), include
+/// that field's value in the `key`.
+pub struct OptionsLoader<Value> {
+    key: String,
+    function: Rc<dyn Fn() -> OptionsFuture<Value>>,
+}
+
+impl<Value> OptionsLoader<Value> {
+    /// Create a new `OptionsLoader`, identified by `key`.
+    pub fn new<K, F, Fut>(key: K, function: F) -> Self
+    where
+        K: Into<String>,
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<Vec<SelectOption<Value>>, String>> + 'static,
+    {
+        Self {
+            key: key.into(),
+            function: Rc::new(move || Box::pin(function())),
+        }
+    }
+
+    /// The key which identifies the options this loader loads.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Load the options.
+    pub fn load(&self) -> OptionsFuture<Value> {
+        (self.function)()
+    }
+}
+
+impl<Value> Clone for OptionsLoader<Value> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            function: Rc::clone(&self.function),
+        }
+    }
+}
+
+impl<Value> PartialEq for OptionsLoader<Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<Value> Debug for OptionsLoader<Value> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OptionsLoader(key: {:?})", self.key)
+    }
+}
+
 #[derive(Debug)]
 pub struct MultiValueField<Value, Key, Props, Renderer>
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + 'static,
     Props: MultiValueFieldProps<Value, Key> + 'static,
     Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized + 'static,
@@ -27,6 +93,25 @@ where
     pub value: Option<Value>,
     pub validation_errors: ValidationErrors<Key>,
     pub display_validation_errors: ValidationErrors<Key>,
+    /// Whether a validation has completed since the value was last
+    /// updated, a validation was last requested, or the validation
+    /// errors were last cleared.
+    pub validated: bool,
+    /// The options produced by [MultiValueFieldProps::options_loader],
+    /// if they have been loaded.
+    pub loaded_options: Option<Vec<SelectOption<Value>>>,
+    /// Whether the options are currently being loaded.
+    pub loading: bool,
+    /// Incremented each time the options are loaded, to identify and
+    /// ignore options which arrive from an outdated loader.
+    load_id: u64,
+    /// An error produced when the options failed to load.
+    load_errors: ValidationErrors<Key>,
+    /// Unique ids for the elements of this field.
+    pub ids: FieldIds,
+    /// A reference to the element which renderers should attach to
+    /// this field's control, see [MultiValueFieldRenderer::focus].
+    pub node_ref: NodeRef,
     pub props: Props,
     pub form_link: FormFieldLink<Key>,
     pub link: ComponentLink<Self>,
@@ -34,10 +119,10 @@ where
 
 pub trait MultiValueFieldProps<Value, Key>: Properties + FieldProps<Key> + PartialEq
 where
-    Key: FieldKey,
+    Key: FieldKey + 'static,
 {
     /// The options available to select with this field.
-    fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Value> + 'a>;
+    fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a SelectOption<Value>> + 'a>;
     /// Whether to show the label. By default this is `true`. By
     /// default the label text comes fom the `field_key`'s `Display`
     /// implementation, however it can be overriden with the `label`
@@ -50,16 +135,36 @@ where
     fn validator(&self) -> &AsyncValidator<Option<Value>, Key>;
     /// The default selected value.
     fn selected(&self) -> &Option<Value>;
-    /// A callback for when this field changes.
-    fn onupdate(&self) -> &Callback<Value>;
+    /// A callback for when this field changes. This emits `None` when
+    /// the selected value is cleared.
+    fn onupdate(&self) -> &Callback<Option<Value>>;
+    /// Whether to display the field with the `is-success` style once
+    /// a validation has completed without any errors. By default
+    /// this is `false`.
+    fn show_success(&self) -> bool {
+        false
+    }
+    /// An asynchronous function to load the options available to
+    /// select with this field, which replace
+    /// [MultiValueFieldProps::options] once they have loaded. By
+    /// default this is `None`.
+    fn options_loader(&self) -> Option<&OptionsLoader<Value>> {
+        None
+    }
+    /// A callback for when the options start (`true`) or finish
+    /// (`false`) loading with [MultiValueFieldProps::options_loader].
+    /// By default this is `None`.
+    fn onloading(&self) -> Option<&Callback<bool>> {
+        None
+    }
 }
 
 impl<Value, Key, Props, Renderer> MultiValueField<Value, Key, Props, Renderer>
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + 'static,
     Props: MultiValueFieldProps<Value, Key> + 'static,
-    Renderer: MultiValueFieldRenderer<Value, Key, Props>,
+    Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized,
 {
     pub fn label(&self) -> Option<String> {
         if self.props.show_label() {
@@ -71,18 +176,142 @@ where
             None
         }
     }
+
+    /// The options available to select with this field, the loaded
+    /// options if [MultiValueFieldProps::options_loader] has
+    /// produced them, otherwise [MultiValueFieldProps::options].
+    pub fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a SelectOption<Value>> + 'a> {
+        match &self.loaded_options {
+            Some(options) => Box::new(options.iter()),
+            None => self.props.options(),
+        }
+    }
+
+    /// Start loading the options using
+    /// [MultiValueFieldProps::options_loader], if there is one.
+    fn load_options(&mut self) -> ShouldRender {
+        let loader = match self.props.options_loader() {
+            Some(loader) => loader.clone(),
+            None => return false,
+        };
+
+        self.load_id += 1;
+        self.set_loading(true);
+        let load_id = self.load_id;
+        self.link.send_future(async move {
+            MultiValueFieldMsg::OptionsLoaded(load_id, loader.load().await)
+        });
+        true
+    }
+
+    /// Set whether the options are loading, and notify
+    /// [MultiValueFieldProps::onloading] if this has changed.
+    fn set_loading(&mut self, loading: bool) {
+        if self.loading != loading {
+            self.loading = loading;
+            if let Some(onloading) = self.props.onloading() {
+                onloading.emit(loading);
+            }
+        }
+    }
+
+    /// Combine the validation errors, the errors from loading the
+    /// options, and the extra errors for display.
+    fn update_display_errors(&mut self) {
+        let mut display_errors = self.validation_errors.clone();
+        display_errors.extend(self.load_errors.clone());
+        display_errors.extend(self.props.extra_errors().clone());
+        self.display_validation_errors = display_errors;
+    }
+
+    /// Report this field's validation errors, including any error
+    /// from loading the options, to the form.
+    fn send_validation_update(&self) {
+        self.form_link
+            .send_form_message(FormMsg::FieldValidationUpdate(
+                self.props.field_key().clone(),
+                reported_errors(&self.validation_errors, &self.load_errors),
+            ));
+    }
+
+    /// Report a change to the errors from loading the options to the
+    /// form, see [options_validation_update].
+    fn send_options_validation_update(&self) {
+        if let Some(errors) =
+            options_validation_update(self.validated, &self.validation_errors, &self.load_errors)
+        {
+            self.form_link
+                .send_form_message(FormMsg::FieldValidationUpdate(
+                    self.props.field_key().clone(),
+                    errors,
+                ));
+        }
+    }
+
+    /// Whether this field should currently be displayed with the
+    /// `is-success` style.
+    pub fn success(&self) -> bool {
+        self.props.show_success()
+            && self.validated
+            && self
+                .display_validation_errors
+                .get(self.props.field_key())
+                .is_none()
+    }
+}
+
+/// The errors which a field reports to the form: its validation
+/// errors, along with any error from loading its options.
+fn reported_errors<Key: FieldKey>(
+    validation_errors: &ValidationErrors<Key>,
+    load_errors: &ValidationErrors<Key>,
+) -> ValidationErrors<Key> {
+    let mut errors = validation_errors.clone();
+    errors.extend(load_errors.clone());
+    errors
+}
+
+/// The errors which a field reports to the form when its options
+/// have been loaded or removed. A field which has not completed a
+/// validation reports nothing, because the form treats any report as
+/// a completed validation, and could otherwise submit before the
+/// field's validator has finished. The errors from loading the
+/// options are reported along with the validation errors once the
+/// validation completes.
+fn options_validation_update<Key: FieldKey>(
+    validated: bool,
+    validation_errors: &ValidationErrors<Key>,
+    load_errors: &ValidationErrors<Key>,
+) -> Option<ValidationErrors<Key>> {
+    if validated {
+        Some(reported_errors(validation_errors, load_errors))
+    } else {
+        None
+    }
 }
 
 pub enum MultiValueFieldMsg<Value, Key> {
-    Update(Value),
+    /// Update the selected value, or clear it if `None`.
+    Update(Option<Value>),
     Validate,
     ValidationErrors(ValidationErrors<Key>),
     ClearValidationErrors,
+    /// Move the browser's focus to this field, see
+    /// [MultiValueFieldRenderer::focus].
+    Focus,
+    /// Re-render the field, because the [Form](super::Form)'s
+    /// shared configuration (such as its error renderer) has changed.
+    Rerender,
+    /// Load the options using
+    /// [MultiValueFieldProps::options_loader].
+    LoadOptions,
+    /// The options produced by the load with the specified id.
+    OptionsLoaded(u64, Result<Vec<SelectOption<Value>>, String>),
 }
 
 pub struct MultiValueFieldLink<Value, Key, Props, Renderer>
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + 'static,
     Props: MultiValueFieldProps<Value, Key> + 'static,
     Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized + 'static,
@@ -94,7 +323,7 @@ where
 impl<Value, Key, Props, Renderer> Debug for MultiValueFieldLink<Value, Key, Props, Renderer>
 where
     Key: FieldKey + 'static,
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Props: MultiValueFieldProps<Value, Key> + 'static,
     Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized,
 {
@@ -103,11 +332,13 @@ where
     }
 }
 
-impl<Value, Key> Into<MultiValueFieldMsg<Value, Key>> for FieldMsg {
-    fn into(self) -> MultiValueFieldMsg<Value, Key> {
-        match self {
+impl<Value, Key> From<FieldMsg> for MultiValueFieldMsg<Value, Key> {
+    fn from(msg: FieldMsg) -> Self {
+        match msg {
             FieldMsg::Validate => MultiValueFieldMsg::Validate,
             FieldMsg::ClearValidationErrors => MultiValueFieldMsg::ClearValidationErrors,
+            FieldMsg::Focus => MultiValueFieldMsg::Focus,
+            FieldMsg::Rerender => MultiValueFieldMsg::Rerender,
         }
     }
 }
@@ -115,7 +346,7 @@ impl<Value, Key> Into<MultiValueFieldMsg<Value, Key>> for FieldMsg {
 impl<Value, Key, Props, Renderer> FieldLink<Key>
     for MultiValueFieldLink<Value, Key, Props, Renderer>
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + 'static,
     Props: MultiValueFieldProps<Value, Key> + Properties + FieldProps<Key> + 'static,
     Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized,
@@ -130,16 +361,23 @@ where
 
 pub trait MultiValueFieldRenderer<Value, Key, Props>
 where
-    Value: Clone + PartialEq + ToString + Display + Debug,
-    Key: FieldKey,
+    Value: Clone + PartialEq + Debug,
+    Key: FieldKey + 'static,
     Props: MultiValueFieldProps<Value, Key> + 'static,
 {
     fn render(field: &MultiValueField<Value, Key, Props, Self>) -> Html;
+
+    /// Move the browser's focus to the field. By default this
+    /// focuses the element referenced by
+    /// [MultiValueField::node_ref].
+    fn focus(field: &MultiValueField<Value, Key, Props, Self>) {
+        focus_node(&field.node_ref);
+    }
 }
 
 impl<Value, Key, Props, Renderer> Component for MultiValueField<Value, Key, Props, Renderer>
 where
-    Value: Clone + PartialEq + ToString + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + 'static,
     Props: MultiValueFieldProps<Value, Key> + 'static,
     Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized + 'static,
@@ -156,10 +394,19 @@ where
         };
         form_link.register_field(Rc::new(field_link));
 
+        link.send_message(MultiValueFieldMsg::LoadOptions);
+
         MultiValueField {
             value: props.selected().clone(),
             validation_errors: ValidationErrors::default(),
             display_validation_errors: props.extra_errors().clone(),
+            validated: false,
+            loaded_options: None,
+            loading: false,
+            load_id: 0,
+            load_errors: ValidationErrors::default(),
+            ids: FieldIds::new(),
+            node_ref: NodeRef::default(),
             props,
             form_link,
             link,
@@ -169,7 +416,8 @@ where
     fn update(&mut self, msg: MultiValueFieldMsg<Value, Key>) -> ShouldRender {
         match msg {
             MultiValueFieldMsg::Update(value) => {
-                self.value = Some(value.clone());
+                self.value = value.clone();
+                self.validated = false;
                 self.props.onupdate().emit(value);
                 self.props
                     .form_link()
@@ -178,6 +426,7 @@ where
                 true
             }
             MultiValueFieldMsg::Validate => {
+                self.validated = false;
                 let validate_future = self.validate_future_or_empty();
                 self.link.send_future(async move {
                     let validation_errors = validate_future.await;
@@ -187,28 +436,58 @@ where
                 false
             }
             MultiValueFieldMsg::ValidationErrors(errors) => {
-                self.validation_errors = errors.clone();
-
-                let mut display_errors = errors;
-                display_errors.extend(self.props.extra_errors().clone());
-                self.display_validation_errors = display_errors;
-
-                self.form_link
-                    .send_form_message(FormMsg::FieldValidationUpdate(
-                        self.props.field_key().clone(),
-                        self.validation_errors.clone(),
-                    ));
+                self.validation_errors = errors;
+                self.validated = true;
+                self.update_display_errors();
+                self.send_validation_update();
                 true
             }
             MultiValueFieldMsg::ClearValidationErrors => {
                 self.validation_errors = ValidationErrors::default();
-                self.display_validation_errors = self.props.extra_errors().clone();
+                self.validated = false;
+                self.update_display_errors();
+                self.send_validation_update();
+                true
+            }
+            MultiValueFieldMsg::Focus => {
+                Renderer::focus(self);
+                false
+            }
+            MultiValueFieldMsg::Rerender => true,
+            MultiValueFieldMsg::LoadOptions => self.load_options(),
+            MultiValueFieldMsg::OptionsLoaded(load_id, result) => {
+                if load_id != self.load_id {
+                    return false;
+                }
+                self.set_loading(false);
 
-                self.form_link
-                    .send_form_message(FormMsg::FieldValidationUpdate(
-                        self.props.field_key().clone(),
-                        self.validation_errors.clone(),
-                    ));
+                match result {
+                    Ok(options) => {
+                        self.load_errors = ValidationErrors::default();
+                        let value_available = match &self.value {
+                            Some(value) => options.iter().any(|option| &option.value == value),
+                            None => true,
+                        };
+                        self.loaded_options = Some(options);
+                        self.update_display_errors();
+                        self.send_options_validation_update();
+
+                        // The selected value may no longer be
+                        // available, for example if the options depend
+                        // on another field.
+                        if !value_available {
+                            self.update(MultiValueFieldMsg::Update(None));
+                        }
+                    }
+                    Err(message) => {
+                        let field_key = self.props.field_key().clone();
+                        self.load_errors = ValidationError::new(field_key, OPTIONS_LOAD_ERROR)
+                            .with_message(move |_| message.clone())
+                            .into();
+                        self.update_display_errors();
+                        self.send_options_validation_update();
+                    }
+                }
                 true
             }
         }
@@ -220,13 +499,27 @@ where
 
     fn change(&mut self, props: Props) -> ShouldRender {
         let link = self.link.clone();
+        let reload = self.props.options_loader() != props.options_loader();
 
-        self.props.neq_assign_field(props, move |new_props| {
+        let should_render = self.props.neq_assign_field(props, move |new_props| {
             Rc::new(MultiValueFieldLink {
                 field_key: new_props.field_key().clone(),
                 link: link.clone(),
             })
-        })
+        });
+
+        if reload {
+            if self.props.options_loader().is_none() {
+                self.loaded_options = None;
+                self.set_loading(false);
+                self.load_errors = ValidationErrors::default();
+                self.update_display_errors();
+                self.send_options_validation_update();
+            }
+            self.load_options() || should_render
+        } else {
+            should_render
+        }
     }
 }
 
@@ -234,7 +527,7 @@ impl<Value, Key, Props, Renderer> AsyncValidatable<Key>
     for MultiValueField<Value, Key, Props, Renderer>
 where
     Key: FieldKey,
-    Value: Clone + PartialEq + Display + Debug,
+    Value: Clone + PartialEq + Debug,
     Props: MultiValueFieldProps<Value, Key>,
     Renderer: MultiValueFieldRenderer<Value, Key, Props> + ?Sized,
 {
@@ -249,7 +542,7 @@ where
 impl<Value, Key, Props, Renderer> FormField<Key> for MultiValueField<Value, Key, Props, Renderer>
 where
     Key: FieldKey + 'static,
-    Value: Clone + PartialEq + Display + Debug,
+    Value: Clone + PartialEq + Debug,
     Props: MultiValueFieldProps<Value, Key>,
     Renderer: MultiValueFieldRenderer<Value, Key, Props>,
 {
@@ -257,6 +550,56 @@ where
         &self.validation_errors
     }
     fn field_key(&self) -> &Key {
-        &self.props.field_key()
+        self.props.field_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{options_validation_update, reported_errors, OPTIONS_LOAD_ERROR};
+    use crate::components::form::form_component::all_validated;
+
+    use form_validation::{ValidationError, ValidationErrors};
+    use std::collections::HashMap;
+
+    #[test]
+    fn pending_submit_waits_for_validator_after_options_load() {
+        let field_keys = vec!["country", "city"];
+
+        // `FormMsg::ValidateThenSubmit` clears the form's errors and
+        // asks every field to validate, and "country" completes its
+        // validation first.
+        let mut form_errors = HashMap::new();
+        form_errors.insert("country", ValidationErrors::default());
+
+        // The options of "city" fail to load while its validator is
+        // still running.
+        let validation_errors = ValidationErrors::default();
+        let load_errors: ValidationErrors<&str> =
+            ValidationError::new("city", OPTIONS_LOAD_ERROR).into();
+        if let Some(errors) = options_validation_update(false, &validation_errors, &load_errors) {
+            form_errors.insert("city", errors);
+        }
+        assert!(!all_validated(&form_errors, &field_keys));
+
+        // Once the validator completes, the load error is reported
+        // along with the validation errors.
+        form_errors.insert("city", reported_errors(&validation_errors, &load_errors));
+        assert!(all_validated(&form_errors, &field_keys));
+        assert_eq!(form_errors["city"].len(), 1);
+    }
+
+    #[test]
+    fn validated_field_reports_options_load() {
+        let validation_errors: ValidationErrors<&str> =
+            ValidationError::new("city", "REQUIRED").into();
+        let load_errors = ValidationError::new("city", OPTIONS_LOAD_ERROR).into();
+
+        let errors = options_validation_update(true, &validation_errors, &load_errors);
+        assert_eq!(errors.map(|errors| errors.len()), Some(2));
+
+        let errors =
+            options_validation_update(true, &validation_errors, &ValidationErrors::default());
+        assert_eq!(errors.map(|errors| errors.len()), Some(1));
     }
 }
@@ -4,15 +4,18 @@ use super::{
     FieldProps,
 };
 
-use crate::components::form::{FieldKey, FormFieldLink};
+use super::focus::focus_element;
+use super::help::{errors_view, help_view, success_help_view};
+
+use crate::components::form::{FieldKey, FormFieldLink, SharedErrorRenderer};
+use crate::components::select::SelectOption;
 
 use form_validation::{AsyncValidator, ValidationErrors};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::{html, Callback, ChangeData, Html, Properties};
 
-use std::{
-    fmt::{Debug, Display},
-    marker::PhantomData,
-};
+use std::{fmt::Debug, marker::PhantomData};
 
 /// This is a rather heavy generic component, for large projects
 /// consider using String/&str for both the value and the key in forms
@@ -20,7 +23,7 @@ use std::{
 pub type RadioField<Value, Key> =
     MultiValueField<Value, Key, RadioFieldProps<Value, Key>, RadioFieldRenderer<Value, Key>>;
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
 pub enum Layout {
     /// Uses the following layout:
     ///
@@ -36,15 +39,10 @@ pub enum Layout {
     /// ```html
     /// <label><input/></label>
     /// ```
+    #[default]
     InputInLabel,
 }
 
-impl Default for Layout {
-    fn default() -> Self {
-        Self::InputInLabel
-    }
-}
-
 /// [Properties](yew::Component::Properties) for [RadioField].
 #[derive(PartialEq, Clone, Properties, Debug)]
 pub struct RadioFieldProps<Value, Key>
@@ -56,9 +54,14 @@ where
     pub field_key: Key,
     /// The link to the form that this field belongs to.
     pub form_link: FormFieldLink<Key>,
-    /// The options available to this select field.
-    pub options: Vec<Value>,
+    /// The options available to this radio field. Options can be
+    /// created from values implementing `Display` using
+    /// `SelectOption::from`, or with a separate label using
+    /// [SelectOption::new]. [SelectOption::group] is ignored by this
+    /// field.
+    pub options: Vec<SelectOption<Value>>,
     /// (Optional) List of options which should be disabled.
+    #[deprecated(note = "use `SelectOption::disabled` in `options` instead")]
     #[prop_or_default]
     pub disabled_options: Vec<Value>,
     /// Whether to show the field label. By default this is `true`. By
@@ -79,7 +82,7 @@ where
     pub validator: AsyncValidator<Option<Value>, Key>,
     /// (Optional) A callback for when this field changes.
     #[prop_or_default]
-    pub onupdate: Callback<Value>,
+    pub onupdate: Callback<Option<Value>>,
     /// (Optional) Whether to validate when the field is updated.
     #[prop_or(true)]
     pub validate_on_update: bool,
@@ -87,6 +90,11 @@ where
     /// are not reported to the `Form`.
     #[prop_or_default]
     pub extra_errors: ValidationErrors<Key>,
+    /// (Optional) Override the [ErrorRenderer](super::ErrorRenderer)
+    /// provided by the `Form` for rendering this field's validation
+    /// errors.
+    #[prop_or_default]
+    pub error_renderer: Option<SharedErrorRenderer<Key>>,
     /// (Optional) Classes to apply to each item's `<label>`. Default:
     /// `["radio"]`.
     #[prop_or(vec!["radio".to_string()])]
@@ -104,6 +112,25 @@ where
     /// Default: `["is-inline"]`.
     #[prop_or(vec!["is-inline".to_string()])]
     pub input_div_classes: Vec<String>,
+    /// (Optional) Whether this field is required, which is
+    /// announced to assistive technologies with the `aria-required`
+    /// attribute. This does not perform any validation, see
+    /// `validator`. By default this is `false`.
+    #[prop_or(false)]
+    pub required: bool,
+    /// (Optional) Help text to display beneath the field.
+    #[prop_or_default]
+    pub help: Option<String>,
+    /// (Optional) Whether to apply the `is-success` style to this
+    /// field once a validation has completed without any errors. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub show_success: bool,
+    /// (Optional) Help text to display beneath the field when
+    /// `show_success` is `true`, and a validation has completed
+    /// without any errors.
+    #[prop_or_default]
+    pub success_help: Option<String>,
 }
 
 impl<Value, Key> FieldProps<Key> for RadioFieldProps<Value, Key>
@@ -120,6 +147,9 @@ where
     fn extra_errors(&self) -> &ValidationErrors<Key> {
         &self.extra_errors
     }
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &self.error_renderer
+    }
 }
 
 impl<Value, Key> MultiValueFieldProps<Value, Key> for RadioFieldProps<Value, Key>
@@ -127,7 +157,7 @@ where
     Key: FieldKey + PartialEq + 'static,
     Value: Clone + PartialEq,
 {
-    fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Value> + 'a> {
+    fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a SelectOption<Value>> + 'a> {
         Box::new(self.options.iter())
     }
 
@@ -147,9 +177,13 @@ where
         &self.selected
     }
 
-    fn onupdate(&self) -> &Callback<Value> {
+    fn onupdate(&self) -> &Callback<Option<Value>> {
         &self.onupdate
     }
+
+    fn show_success(&self) -> bool {
+        self.show_success
+    }
 }
 
 pub struct RadioFieldRenderer<Value, Key> {
@@ -159,14 +193,14 @@ pub struct RadioFieldRenderer<Value, Key> {
 
 impl<Value, Key> RadioFieldRenderer<Value, Key>
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + PartialEq + 'static,
 {
     /// Create an `input` onchange handler for a specific `Value`.
     fn onchange_value(value: Value) -> impl Fn(ChangeData) -> MultiValueFieldMsg<Value, Key> {
         move |change_data: ChangeData| match change_data {
             ChangeData::Value(change_value) => match change_value.as_str() {
-                "on" => MultiValueFieldMsg::Update(value.clone()),
+                "on" => MultiValueFieldMsg::Update(Some(value.clone())),
                 _ => {
                     panic!("Unexpected onchange value: {}.", change_value,);
                 }
@@ -179,38 +213,36 @@ where
 
     fn input(
         field: &MultiValueField<Value, Key, RadioFieldProps<Value, Key>, Self>,
-        value: Value,
+        index: usize,
+        option: &SelectOption<Value>,
     ) -> Html {
-        let selected = field.value.as_ref() == Some(&value);
-        let disabled = field
-            .props
-            .disabled_options
-            .iter()
-            .find(|v| v == &&value)
-            .is_some();
-        let label = value.to_string();
+        let selected = field.value.as_ref() == Some(&option.value);
+        #[allow(deprecated)]
+        let disabled = option.disabled || field.props.disabled_options.contains(&option.value);
+        let label = option.label.clone();
 
-        let onchange = field.link.callback(Self::onchange_value(value));
+        let onchange = field
+            .link
+            .callback(Self::onchange_value(option.value.clone()));
         let field_name = field.props.field_key.to_string();
+        let id = field.ids.option(index);
 
         match field.props.layout {
             Layout::SideBySideInDiv => {
-                let id = uuid::Uuid::new_v4();
-
                 // This structure is used because it is more flexible for
                 // custom css layouts than `<label><input/></label>`.
                 html! {
                     <div class=field.props.input_div_classes.clone()>
                         <input
                             onchange=onchange
-                            id=id.to_string()
+                            id=id.clone()
                             class=field.props.input_classes.clone()
                             type="radio"
                             name=field_name
                             checked=selected
                             disabled=disabled/>
                         <label
-                            for=id.to_string()
+                            for=id
                             class=field.props.input_label_classes.clone()
                             disabled=disabled>
                             { label }
@@ -225,6 +257,7 @@ where
                         disabled=disabled>
                         <input
                             onchange=onchange
+                            id=id
                             class=field.props.input_classes.clone()
                             type="radio"
                             name=field_name
@@ -241,20 +274,32 @@ where
 impl<Value, Key> MultiValueFieldRenderer<Value, Key, RadioFieldProps<Value, Key>>
     for RadioFieldRenderer<Value, Key>
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + PartialEq + 'static,
 {
     fn render(field: &MultiValueField<Value, Key, RadioFieldProps<Value, Key>, Self>) -> Html {
         let mut classes = vec![];
 
-        let validation_error =
-            if let Some(errors) = field.display_validation_errors.get(&field.props.field_key) {
-                classes.push("is-danger".to_string());
-                let error_message = errors.to_string();
-                html! {<p class="help is-danger">{ error_message }</p>}
-            } else {
-                html! {}
-            };
+        let errors = field.display_validation_errors.get(&field.props.field_key);
+        let has_errors = errors.is_some();
+
+        let validation_error = if let Some(errors) = errors {
+            classes.push("is-danger".to_string());
+            errors_view(&field.ids, field.props.render_errors(&errors))
+        } else {
+            html! {}
+        };
+
+        let success = field.success();
+        let described_by = field.ids.described_by(
+            has_errors,
+            success && field.props.success_help.is_some(),
+            field.props.help.is_some(),
+        );
+
+        if success {
+            classes.push("is-success".to_string());
+        }
 
         let label = field.label();
 
@@ -262,16 +307,25 @@ where
             .props
             .options
             .iter()
-            .map(|value| Self::input(field, value.clone()))
+            .enumerate()
+            .map(|(index, option)| Self::input(field, index, option))
             .collect();
 
+        // A `<fieldset>` is used to group the radio buttons so that
+        // the `<legend>` labels the group as a whole.
         html! {
-            <div class="field">
+            <fieldset
+                ref=field.node_ref.clone()
+                class="field"
+                role="radiogroup"
+                aria-invalid=has_errors.then_some("true")
+                aria-required=field.props.required.then_some("true")
+                aria-describedby=described_by>
                 {
                     match label {
                         Some(label) => {
                             html!{
-                                <label class="label">{ label }</label>
+                                <legend class="label">{ label }</legend>
                             }
                         },
                         None => {
@@ -283,7 +337,27 @@ where
                     { inputs }
                 </div>
                 { validation_error }
-            </div>
+                { success_help_view(&field.ids, success, &field.props.success_help) }
+                { help_view(&field.ids, &field.props.help) }
+            </fieldset>
+        }
+    }
+
+    /// Focuses the selected radio button, or the first enabled radio
+    /// button if none are selected.
+    fn focus(field: &MultiValueField<Value, Key, RadioFieldProps<Value, Key>, Self>) {
+        let fieldset = match field.node_ref.cast::<Element>() {
+            Some(fieldset) => fieldset,
+            None => return,
+        };
+
+        let input = ["input:checked", "input:not(:disabled)"]
+            .iter()
+            .find_map(|selector| fieldset.query_selector(selector).ok().flatten())
+            .and_then(|input| input.dyn_into::<HtmlElement>().ok());
+
+        if let Some(input) = input {
+            focus_element(&input);
         }
     }
 }
@@ -1,15 +1,19 @@
-use crate::components::form::{FieldKey, FormFieldLink};
-use crate::components::select::Select;
+use crate::components::form::{FieldKey, FormFieldLink, SharedErrorRenderer};
+use crate::components::{
+    dropdown::{self, Dropdown, DropdownItem},
+    select::{self, Select, SelectOption},
+};
 
 use form_validation::{AsyncValidator, ValidationErrors};
 use yew::{html, Callback, Html, Properties};
 
+use super::help::{errors_view, help_view, success_help_view};
 use super::{
     multi_value_field::MultiValueField, multi_value_field::MultiValueFieldMsg,
     multi_value_field::MultiValueFieldProps, multi_value_field::MultiValueFieldRenderer,
-    FieldProps,
+    multi_value_field::OptionsLoader, FieldProps,
 };
-use std::fmt::{Debug, Display};
+use std::fmt::Debug;
 
 pub type SelectField<Value, Key> =
     MultiValueField<Value, Key, SelectFieldProps<Value, Key>, SelectFieldRenderer>;
@@ -25,8 +29,27 @@ where
     pub field_key: Key,
     /// The link to the form that this field belongs to.
     pub form_link: FormFieldLink<Key>,
-    /// The options available to this select field.
-    pub options: Vec<Value>,
+    /// (Optional) The options available to this select field.
+    /// Options can be created from values implementing `Display`
+    /// using `SelectOption::from`, or with a separate label using
+    /// [SelectOption::new].
+    #[prop_or_default]
+    pub options: Vec<SelectOption<Value>>,
+    /// (Optional) An asynchronous function to load the options for
+    /// this field, which replace `options` once they have loaded.
+    /// While the options are loading the field is displayed with the
+    /// `is-loading` style, and if loading fails the error is
+    /// displayed on the field and reported to the form as a
+    /// validation error. See [OptionsLoader] for how to reload the
+    /// options.
+    #[prop_or_default]
+    pub options_loader: Option<OptionsLoader<Value>>,
+    /// (Optional) A callback for when the options start (`true`) or
+    /// finish (`false`) loading with `options_loader`, for example to
+    /// display a [Loader](crate::components::loader::Loader) around
+    /// the form.
+    #[prop_or_default]
+    pub onloading: Callback<bool>,
     /// Whether to show the label. By default this is `true`. By
     /// default the label text comes fom the `field_key`'s `Display`
     /// implementation, however it can be overriden with the `label`
@@ -40,12 +63,23 @@ where
     /// (Optional) The default selected value.
     #[prop_or_default]
     pub selected: Option<Value>,
+    /// (Optional) The text of the empty option which is displayed
+    /// when there is no selected value, or `None` to hide the empty
+    /// option. By default this is `"↪"`.
+    #[prop_or(Some("↪".to_string()))]
+    pub placeholder: Option<String>,
+    /// (Optional) Whether the user can choose the empty option to
+    /// clear the selected value. A `validator` can be used to
+    /// require that a value is selected. By default this is `false`.
+    #[prop_or(false)]
+    pub allow_clear: bool,
     /// (Optional) What validator to use for this field.
     #[prop_or_default]
     pub validator: AsyncValidator<Option<Value>, Key>,
-    /// (Optional) A callback for when this field changes.
+    /// (Optional) A callback for when this field changes. This emits
+    /// `None` when the selection is cleared (see `allow_clear`).
     #[prop_or_default]
-    pub onupdate: Callback<Value>,
+    pub onupdate: Callback<Option<Value>>,
     /// (Optional) Whether to validate when the field is updated.
     #[prop_or(true)]
     pub validate_on_update: bool,
@@ -53,6 +87,30 @@ where
     /// are not reported to the `Form`.
     #[prop_or_default]
     pub extra_errors: ValidationErrors<Key>,
+    /// (Optional) Override the [ErrorRenderer](super::ErrorRenderer)
+    /// provided by the `Form` for rendering this field's validation
+    /// errors.
+    #[prop_or_default]
+    pub error_renderer: Option<SharedErrorRenderer<Key>>,
+    /// (Optional) Whether this field is required, which is
+    /// announced to assistive technologies with the `aria-required`
+    /// attribute. This does not perform any validation, see
+    /// `validator`. By default this is `false`.
+    #[prop_or(false)]
+    pub required: bool,
+    /// (Optional) Help text to display beneath the field.
+    #[prop_or_default]
+    pub help: Option<String>,
+    /// (Optional) Whether to apply the `is-success` style to this
+    /// field once a validation has completed without any errors. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub show_success: bool,
+    /// (Optional) Help text to display beneath the field when
+    /// `show_success` is `true`, and a validation has completed
+    /// without any errors.
+    #[prop_or_default]
+    pub success_help: Option<String>,
 }
 
 impl<Value, Key> FieldProps<Key> for SelectFieldProps<Value, Key>
@@ -69,6 +127,9 @@ where
     fn extra_errors(&self) -> &ValidationErrors<Key> {
         &self.extra_errors
     }
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &self.error_renderer
+    }
 }
 
 impl<Value, Key> MultiValueFieldProps<Value, Key> for SelectFieldProps<Value, Key>
@@ -76,7 +137,7 @@ where
     Key: FieldKey + PartialEq + 'static,
     Value: Clone + PartialEq,
 {
-    fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Value> + 'a> {
+    fn options<'a>(&'a self) -> Box<dyn Iterator<Item = &'a SelectOption<Value>> + 'a> {
         Box::new(self.options.iter())
     }
 
@@ -96,9 +157,99 @@ where
         &self.selected
     }
 
-    fn onupdate(&self) -> &Callback<Value> {
+    fn onupdate(&self) -> &Callback<Option<Value>> {
         &self.onupdate
     }
+
+    fn show_success(&self) -> bool {
+        self.show_success
+    }
+
+    fn options_loader(&self) -> Option<&OptionsLoader<Value>> {
+        self.options_loader.as_ref()
+    }
+
+    fn onloading(&self) -> Option<&Callback<bool>> {
+        Some(&self.onloading)
+    }
+}
+
+/// The state of a [SelectField] which is needed to render its
+/// control, computed by [render_select_field].
+struct SelectControl {
+    /// Classes describing the state of the field (`is-danger`,
+    /// `is-success` and `is-loading`) to apply to the control.
+    classes: Vec<String>,
+    has_errors: bool,
+    /// The `aria-describedby` attribute of the control.
+    described_by: Option<String>,
+}
+
+/// Render the label, validation errors and help text which surround
+/// the control of a [SelectField], using `view_control` to render the
+/// control itself. This is shared by the renderers of the
+/// [SelectField] variants.
+fn render_select_field<Value, Key, Renderer, F>(
+    field: &MultiValueField<Value, Key, SelectFieldProps<Value, Key>, Renderer>,
+    view_control: F,
+) -> Html
+where
+    Value: Clone + PartialEq + Debug + 'static,
+    Key: FieldKey + PartialEq + 'static,
+    Renderer: MultiValueFieldRenderer<Value, Key, SelectFieldProps<Value, Key>> + ?Sized,
+    F: FnOnce(SelectControl) -> Html,
+{
+    let mut classes = vec![];
+
+    let errors = field.display_validation_errors.get(&field.props.field_key);
+    let has_errors = errors.is_some();
+
+    let validation_error = if let Some(errors) = errors {
+        classes.push("is-danger".to_string());
+        errors_view(&field.ids, field.props.render_errors(&errors))
+    } else {
+        html! {}
+    };
+
+    let success = field.success();
+    let described_by = field.ids.described_by(
+        has_errors,
+        success && field.props.success_help.is_some(),
+        field.props.help.is_some(),
+    );
+
+    if success {
+        classes.push("is-success".to_string());
+    }
+
+    if field.loading {
+        classes.push("is-loading".to_string());
+    }
+
+    let label = match field.label() {
+        Some(label) => html! {
+            <label class="label" for=field.ids.control()>{ label }</label>
+        },
+        None => html! {},
+    };
+
+    let control = view_control(SelectControl {
+        classes,
+        has_errors,
+        described_by,
+    });
+
+    html! {
+        <div class="field">
+            { label }
+            <div class="control">
+                { control }
+            </div>
+            { validation_error }
+            { success_help_view(&field.ids, success, &field.props.success_help) }
+            { help_view(&field.ids, &field.props.help) }
+        </div>
+    }
 }
 
 pub struct SelectFieldRenderer;
@@ -106,49 +257,133 @@ pub struct SelectFieldRenderer;
 impl<Value, Key> MultiValueFieldRenderer<Value, Key, SelectFieldProps<Value, Key>>
     for SelectFieldRenderer
 where
-    Value: Clone + PartialEq + Display + Debug + 'static,
+    Value: Clone + PartialEq + Debug + 'static,
     Key: FieldKey + PartialEq + 'static,
 {
     fn render(field: &MultiValueField<Value, Key, SelectFieldProps<Value, Key>, Self>) -> Html {
-        let mut classes = vec![];
-
-        let validation_error =
-            if let Some(errors) = field.display_validation_errors.get(&field.props.field_key) {
-                classes.push("is-danger".to_string());
-                let error_message = errors.to_string();
-                html! {<p class="help is-danger">{ error_message }</p>}
-            } else {
-                html! {}
+        let select_onchange = field.link.callback(MultiValueFieldMsg::Update);
+
+        render_select_field(field, |control| {
+            let props = select::Props {
+                selected: field.value.clone(),
+                disabled: false,
+                options: field.options().cloned().collect(),
+                div_classes: control.classes,
+                icon_props: None,
+                size: Default::default(),
+                onchange: select_onchange,
+                placeholder: field.props.placeholder.clone(),
+                allow_clear: field.props.allow_clear,
+                select_ref: field.node_ref.clone(),
+                id: Some(field.ids.control()),
+                aria_invalid: control.has_errors,
+                aria_required: field.props.required,
+                aria_describedby: control.described_by,
             };
 
-        let select_onchange = field.link.callback(MultiValueFieldMsg::Update);
+            html! {
+                <Select<Value> with props/>
+            }
+        })
+    }
+}
+
+/// A [SelectField] variant which is rendered using a styled
+/// [Dropdown] instead of a native `<select>` element. Options which
+/// belong to a [group](SelectOption::group) are displayed beneath a
+/// heading with the group's label.
+pub type DropdownSelectField<Value, Key> =
+    MultiValueField<Value, Key, SelectFieldProps<Value, Key>, DropdownSelectFieldRenderer>;
 
-        let label = field.label();
-
-        html! {
-            <div class="field">
-                {
-                    match label {
-                        Some(label) => {
-                            html!{
-                                <label class="label">{ label }</label>
-                            }
-                        },
-                        None => {
-                            html!{}
-                        }
-                    }
+pub struct DropdownSelectFieldRenderer;
+
+impl DropdownSelectFieldRenderer {
+    /// The items of the dropdown for the field's options. The
+    /// dropdown chooses between `Option<Value>`s so that the empty
+    /// item can clear the selected value. Consecutive options with the
+    /// same group are displayed beneath a heading, separated from the
+    /// other options by dividers.
+    fn items<'a, Value, I>(
+        options: I,
+        allow_clear: bool,
+        placeholder: &str,
+    ) -> Vec<DropdownItem<Option<Value>>>
+    where
+        Value: Clone + 'a,
+        I: Iterator<Item = &'a SelectOption<Value>>,
+    {
+        let mut items: Vec<DropdownItem<Option<Value>>> = Vec::new();
+        if allow_clear {
+            items.push(SelectOption::new(None, placeholder).into());
+        }
+
+        let mut group: Option<&str> = None;
+        for option in options {
+            let option_group = option.group.as_deref();
+            if option_group != group {
+                if !items.is_empty() {
+                    items.push(DropdownItem::Divider);
                 }
-                <div class="control">
-                    <Select<Value>
-                        selected=field.value.clone()
-                        options=field.props.options.clone()
-                        div_classes=classes
-                        onchange=select_onchange
-                        />
-                </div>
-                { validation_error }
-            </div>
+                if let Some(heading) = option_group {
+                    items.push(DropdownItem::Heading(heading.to_string()));
+                }
+                group = option_group;
+            }
+
+            items.push(DropdownItem::Option(SelectOption {
+                value: Some(option.value.clone()),
+                label: option.label.clone(),
+                disabled: option.disabled,
+                group: option.group.clone(),
+            }));
         }
+        items
+    }
+}
+
+impl<Value, Key> MultiValueFieldRenderer<Value, Key, SelectFieldProps<Value, Key>>
+    for DropdownSelectFieldRenderer
+where
+    Value: Clone + PartialEq + Debug + 'static,
+    Key: FieldKey + PartialEq + 'static,
+{
+    fn render(field: &MultiValueField<Value, Key, SelectFieldProps<Value, Key>, Self>) -> Html {
+        let placeholder = field.props.placeholder.clone().unwrap_or_default();
+        let items = Self::items(field.options(), field.props.allow_clear, &placeholder);
+
+        let selected = if field.value.is_some() || field.props.allow_clear {
+            Some(field.value.clone())
+        } else {
+            None
+        };
+
+        let dropdown_onchange = field.link.callback(MultiValueFieldMsg::Update);
+
+        render_select_field(field, |control| {
+            let props = dropdown::Props {
+                items,
+                selected,
+                onchange: dropdown_onchange,
+                label: None,
+                placeholder,
+                icon_props: None,
+                size: Default::default(),
+                disabled: false,
+                is_hoverable: false,
+                is_right: false,
+                is_up: false,
+                div_classes: Vec::new(),
+                button_classes: control.classes,
+                trigger_ref: field.node_ref.clone(),
+                id: Some(field.ids.control()),
+                aria_invalid: control.has_errors,
+                aria_required: field.props.required,
+                aria_describedby: control.described_by,
+            };
+
+            html! {
+                <Dropdown<Option<Value>> with props/>
+            }
+        })
     }
 }
@@ -0,0 +1,183 @@
+//! A [Button] which validates and then submits a [Form](super::Form).
+
+use super::{FieldKey, FormFieldLink, FormMsg, FormState};
+use crate::{
+    classes::{ControlSize, MainColor},
+    components::{
+        button::{self, Button, ButtonType},
+        icon,
+    },
+};
+
+use uuid::Uuid;
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+
+/// A [Button] bound to a [Form](super::Form) using its
+/// [FormFieldLink]. Clicking the button sends a
+/// [FormMsg::ValidateThenSubmit] to the form. The button is displayed
+/// with the `is-loading` style and disabled while the form is
+/// validating its fields, or while it is being submitted (see
+/// [FormProps::submitting](super::FormProps::submitting)).
+#[derive(Debug)]
+pub struct SubmitButton<Key>
+where
+    Key: FieldKey + 'static,
+{
+    state: FormState,
+    /// The id of the listener registered with the
+    /// [SubmitButtonProps::form_link] to observe the form's state.
+    listener_id: Uuid,
+    props: SubmitButtonProps<Key>,
+    link: ComponentLink<Self>,
+}
+
+pub enum SubmitButtonMsg {
+    Submit,
+    StateUpdate(FormState),
+}
+
+/// [Properties](yew::Component::Properties) for [SubmitButton].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct SubmitButtonProps<Key>
+where
+    Key: FieldKey + 'static,
+{
+    /// The link to the form that this button submits.
+    pub form_link: FormFieldLink<Key>,
+    /// The content of the button, typically its text.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The color of the button.
+    #[prop_or_default]
+    pub color: Option<MainColor>,
+    /// (Optional) The size of the button.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) Apply the `is-outlined` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_outlined: bool,
+    /// (Optional) Apply the `is-light` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_light: bool,
+    /// (Optional) Apply the `is-inverted` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_inverted: bool,
+    /// (Optional) Apply the `is-fullwidth` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_fullwidth: bool,
+    /// (Optional) Disable the button. By default this is `false`.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// (Optional) An icon to display before the content of the
+    /// button.
+    #[prop_or_default]
+    pub icon_props: Option<icon::Props>,
+    /// (Optional) Extra classes to apply to the `<button>`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl<Key> SubmitButton<Key>
+where
+    Key: FieldKey + 'static,
+{
+    /// Whether the form is busy validating or submitting.
+    fn busy(&self) -> bool {
+        self.state.validating || self.state.submitting
+    }
+}
+
+impl<Key> Component for SubmitButton<Key>
+where
+    Key: FieldKey + 'static,
+{
+    type Message = SubmitButtonMsg;
+    type Properties = SubmitButtonProps<Key>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let listener_id = props
+            .form_link
+            .register_state_listener(link.callback(SubmitButtonMsg::StateUpdate));
+
+        Self {
+            state: props.form_link.state(),
+            listener_id,
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            SubmitButtonMsg::Submit => {
+                if !self.busy() {
+                    self.props
+                        .form_link
+                        .send_form_message(FormMsg::ValidateThenSubmit);
+                }
+                false
+            }
+            SubmitButtonMsg::StateUpdate(state) => {
+                if self.state != state {
+                    self.state = state;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.form_link != props.form_link {
+                self.props
+                    .form_link
+                    .unregister_state_listener(&self.listener_id);
+                self.listener_id = props
+                    .form_link
+                    .register_state_listener(self.link.callback(SubmitButtonMsg::StateUpdate));
+                self.state = props.form_link.state();
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let busy = self.busy();
+        let onclick = self.link.callback(|_| SubmitButtonMsg::Submit);
+
+        let props = button::Props {
+            children: self.props.children.clone(),
+            color: self.props.color,
+            size: self.props.size,
+            is_outlined: self.props.is_outlined,
+            is_light: self.props.is_light,
+            is_inverted: self.props.is_inverted,
+            is_loading: busy,
+            is_fullwidth: self.props.is_fullwidth,
+            disabled: busy || self.props.disabled,
+            icon_props: self.props.icon_props.clone(),
+            button_type: ButtonType::Button,
+            classes: self.props.classes.clone(),
+            onclick,
+        };
+
+        html! {
+            <Button with props/>
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.props
+            .form_link
+            .unregister_state_listener(&self.listener_id);
+    }
+}
@@ -0,0 +1,648 @@
+//! A form field for entering free-form text tags, such as labels or
+//! keywords. The tags are displayed as `bulma`
+//! [tags](https://bulma.io/documentation/elements/tag/) in front of
+//! the field's `<input>`.
+
+use super::{
+    focus::focus_node,
+    help::{errors_view, help_view, success_help_view},
+    input_field::{Label, Placeholder},
+    FieldIds, FieldKey, FieldLink, FieldMsg, FieldProps, FormField, FormFieldLink, FormMsg,
+    NeqAssignFieldProps, SharedErrorRenderer,
+};
+use crate::classes::{GroupSize, MainColor};
+
+use form_validation::{
+    AsyncValidatable, AsyncValidator, Validation, ValidationError, ValidationErrors, Validator,
+};
+use web_sys::KeyboardEvent;
+use yew::{
+    html, Callback, Component, ComponentLink, Html, InputData, NodeRef, Properties, ShouldRender,
+};
+use yewtil::future::LinkFuture;
+
+use std::{fmt::Debug, future::Future, pin::Pin, rc::Rc};
+
+/// The [ValidationError::type_id] of the error displayed when a
+/// duplicate tag is rejected, see [DuplicateTags::Reject].
+pub const DUPLICATE_TAG_ERROR: &str = "DUPLICATE_TAG";
+
+/// The [ValidationError::type_id] of the error displayed when a tag
+/// is rejected because [TagsInputFieldProps::max_tags] has been
+/// reached.
+pub const MAX_TAGS_ERROR: &str = "MAX_TAGS";
+
+/// See [TagsInputFieldProps::duplicates].
+#[derive(Clone, Debug, Copy, PartialEq, Default)]
+pub enum DuplicateTags {
+    /// Allow the same tag to be entered multiple times.
+    Allow,
+    /// Silently discard tags which have already been entered.
+    #[default]
+    Ignore,
+    /// Keep tags which have already been entered in the `<input>`,
+    /// and display a validation error with the type id
+    /// [DUPLICATE_TAG_ERROR].
+    Reject,
+}
+
+/// A form field for entering a list of text tags. A tag is committed
+/// when the user presses `Enter` or types a comma, and can be removed
+/// using the delete button on the tag, or by pressing `Backspace` in
+/// the empty `<input>`.
+///
+/// The whole list of tags is the value of this field which is
+/// validated and reported to the [Form](super::Form).
+#[derive(Debug)]
+pub struct TagsInputField<Key>
+where
+    Key: FieldKey + 'static,
+{
+    tags: Vec<String>,
+    /// The text currently in the `<input>` which has not yet been
+    /// committed as a tag.
+    pending: String,
+    /// Errors for the pending tag which was rejected, these are not
+    /// reported to the form.
+    tag_errors: ValidationErrors<Key>,
+    validation_errors: ValidationErrors<Key>,
+    display_validation_errors: ValidationErrors<Key>,
+    /// Whether a validation has completed since the value was last
+    /// updated, or the validation errors were last cleared.
+    validated: bool,
+    ids: FieldIds,
+    input_ref: NodeRef,
+    props: TagsInputFieldProps<Key>,
+    form_link: FormFieldLink<Key>,
+    link: ComponentLink<Self>,
+}
+
+impl<Key> TagsInputField<Key>
+where
+    Key: FieldKey + 'static,
+{
+    fn label(&self) -> Option<String> {
+        match &self.props.label {
+            Label::FieldKey => Some(self.props.field_key.to_string()),
+            Label::Text(text) => Some(text.clone()),
+            Label::None => None,
+        }
+    }
+
+    /// Whether this field should currently be displayed with the
+    /// `is-success` style.
+    fn success(&self) -> bool {
+        self.props.show_success
+            && self.validated
+            && self.tag_errors.is_empty()
+            && self
+                .display_validation_errors
+                .get(&self.props.field_key)
+                .is_none()
+    }
+
+    /// Whether the maximum number of tags has been reached.
+    fn full(&self) -> bool {
+        match self.props.max_tags {
+            Some(max_tags) => self.tags.len() >= max_tags,
+            None => false,
+        }
+    }
+
+    /// The error displayed when a tag is rejected because the
+    /// maximum number of tags has been reached.
+    fn max_tags_error(&self) -> ValidationErrors<Key> {
+        let max_tags = self.props.max_tags.unwrap_or_default();
+        ValidationError::new(self.props.field_key.clone(), MAX_TAGS_ERROR)
+            .with_message(move |_| format!("A maximum of {} tags can be added", max_tags))
+            .into()
+    }
+
+    /// Attempt to add a `tag`. Returns `Err` with the reason if the
+    /// tag was rejected.
+    fn add_tag(&mut self, tag: &str) -> Result<(), ValidationErrors<Key>> {
+        let tag = tag.trim();
+
+        if tag.is_empty() {
+            return Ok(());
+        }
+
+        if self.full() {
+            return Err(self.max_tags_error());
+        }
+
+        if self.tags.iter().any(|t| t == tag) {
+            match self.props.duplicates {
+                DuplicateTags::Allow => {}
+                DuplicateTags::Ignore => return Ok(()),
+                DuplicateTags::Reject => {
+                    let tag = tag.to_string();
+                    return Err(ValidationError::new(
+                        self.props.field_key.clone(),
+                        DUPLICATE_TAG_ERROR,
+                    )
+                    .with_message(move |_| format!("\"{}\" has already been added", tag))
+                    .into());
+                }
+            }
+        }
+
+        let tag = tag.to_string();
+        self.props
+            .tag_validator
+            .validate_value(&tag, &self.props.field_key)?;
+
+        self.tags.push(tag);
+        Ok(())
+    }
+
+    /// Notify the form and the `onupdate` callback that the tags
+    /// have changed, and validate if required.
+    fn tags_updated(&mut self) {
+        self.validated = false;
+        self.props.onupdate.emit(self.tags.clone());
+        self.form_link
+            .send_form_message(FormMsg::FieldValueUpdate(self.props.field_key.clone()));
+
+        if self.props.validate_on_update {
+            self.update(TagsInputFieldMsg::Validate);
+        }
+    }
+
+    fn view_tag(&self, index: usize, tag: &str) -> Html {
+        let mut tag_classes = vec!["tag".to_string()];
+
+        if let Some(color) = &self.props.tag_color {
+            tag_classes.push(color.class());
+        }
+
+        let onclick = self
+            .link
+            .callback(move |_| TagsInputFieldMsg::RemoveTag(index));
+
+        let mut tags_classes = vec!["tags".to_string(), "has-addons".to_string()];
+        tags_classes.extend(self.props.tag_size.tags_class());
+
+        html! {
+            <div class="control">
+                <div class=tags_classes>
+                    <span class=tag_classes>{ tag }</span>
+                    <button
+                        type="button"
+                        class="tag is-delete"
+                        aria-label=format!("Remove {}", tag)
+                        disabled=self.props.disabled
+                        onclick=onclick/>
+                </div>
+            </div>
+        }
+    }
+}
+
+pub enum TagsInputFieldMsg<Key> {
+    /// The text in the `<input>` has changed.
+    Input(String),
+    /// Commit the text in the `<input>` as a tag.
+    Commit,
+    /// Remove the tag with the specified index.
+    RemoveTag(usize),
+    /// Remove the last tag.
+    RemoveLastTag,
+    /// Validate this field, sends a [FormMsg::FieldValidationUpdate]
+    /// to the `form_link` upon completion.
+    Validate,
+    ValidationErrors(ValidationErrors<Key>),
+    ClearValidationErrors,
+    /// Move the browser's focus to the `<input>` element.
+    Focus,
+    /// Re-render the field, because the [Form](super::Form)'s
+    /// shared configuration (such as its error renderer) has changed.
+    Rerender,
+}
+
+impl<Key> From<FieldMsg> for TagsInputFieldMsg<Key> {
+    fn from(msg: FieldMsg) -> Self {
+        match msg {
+            FieldMsg::Validate => TagsInputFieldMsg::Validate,
+            FieldMsg::ClearValidationErrors => TagsInputFieldMsg::ClearValidationErrors,
+            FieldMsg::Focus => TagsInputFieldMsg::Focus,
+            FieldMsg::Rerender => TagsInputFieldMsg::Rerender,
+        }
+    }
+}
+
+pub struct TagsInputFieldLink<Key>
+where
+    Key: FieldKey + 'static,
+{
+    pub field_key: Key,
+    pub link: ComponentLink<TagsInputField<Key>>,
+}
+
+impl<Key> Debug for TagsInputFieldLink<Key>
+where
+    Key: FieldKey + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TagsInputFieldLink<{0:?}>", self.field_key())
+    }
+}
+
+impl<Key> FieldLink<Key> for TagsInputFieldLink<Key>
+where
+    Key: FieldKey + 'static,
+{
+    fn field_key(&self) -> &Key {
+        &self.field_key
+    }
+    fn send_message(&self, msg: FieldMsg) {
+        self.link.send_message(msg)
+    }
+}
+
+/// [Properties](yew::Component::Properties) for [TagsInputField].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct TagsInputFieldProps<Key>
+where
+    Key: FieldKey + 'static,
+{
+    /// The key used to refer to this field.
+    pub field_key: Key,
+    /// The link to the form that this field belongs to.
+    pub form_link: FormFieldLink<Key>,
+    /// (Optional) The initial tags.
+    #[prop_or_default]
+    pub tags: Vec<String>,
+    /// Whether or not the input for this field is disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// (Optional) Set the label text. By default this is
+    /// [Label::FieldKey].
+    #[prop_or_default]
+    pub label: Label,
+    /// (Optional) Placeholder text. By default this is
+    /// [Placeholder::None].
+    #[prop_or_default]
+    pub placeholder: Placeholder,
+    /// (Optional) How to handle a tag which has already been
+    /// entered. By default this is [DuplicateTags::Ignore].
+    #[prop_or_default]
+    pub duplicates: DuplicateTags,
+    /// (Optional) The maximum number of tags which can be entered.
+    /// Once this has been reached, tags which are entered (or
+    /// pasted) are kept in the `<input>`, and an error with the type
+    /// id [MAX_TAGS_ERROR] is displayed. The `<input>` stays enabled,
+    /// so tags can still be removed with `Backspace`. By default
+    /// there is no limit.
+    #[prop_or_default]
+    pub max_tags: Option<usize>,
+    /// (Optional) A validator which each tag needs to pass before it
+    /// is added. Errors from this validator are displayed, but they
+    /// are not reported to the `Form`.
+    #[prop_or_default]
+    pub tag_validator: Validator<String, Key>,
+    /// (Optional) What validator to use for the list of tags in this
+    /// field.
+    #[prop_or_default]
+    pub validator: AsyncValidator<Vec<String>, Key>,
+    /// (Optional) A callback for when the tags in this field change.
+    #[prop_or_default]
+    pub onupdate: Callback<Vec<String>>,
+    /// (Optional) Whether to validate when the field is updated.
+    #[prop_or(true)]
+    pub validate_on_update: bool,
+    /// (Optional) The color of the tags.
+    #[prop_or_default]
+    pub tag_color: Option<MainColor>,
+    /// (Optional) The size of the tags.
+    #[prop_or_default]
+    pub tag_size: GroupSize,
+    /// (Optional) Extra validation errors to display. These errors
+    /// are not reported to the `Form`.
+    #[prop_or_default]
+    pub extra_errors: ValidationErrors<Key>,
+    /// (Optional) Override the [ErrorRenderer](super::ErrorRenderer)
+    /// provided by the `Form` for rendering this field's validation
+    /// errors.
+    #[prop_or_default]
+    pub error_renderer: Option<SharedErrorRenderer<Key>>,
+    /// (Optional) Whether this field is required, which is
+    /// announced to assistive technologies with the `aria-required`
+    /// attribute. This does not perform any validation, see
+    /// `validator`. By default this is `false`.
+    #[prop_or(false)]
+    pub required: bool,
+    /// (Optional) Help text to display beneath the field.
+    #[prop_or_default]
+    pub help: Option<String>,
+    /// (Optional) Whether to apply the `is-success` style to this
+    /// field once a validation has completed without any errors. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub show_success: bool,
+    /// (Optional) Help text to display beneath the field when
+    /// `show_success` is `true`, and a validation has completed
+    /// without any errors.
+    #[prop_or_default]
+    pub success_help: Option<String>,
+}
+
+impl<Key> FieldProps<Key> for TagsInputFieldProps<Key>
+where
+    Key: FieldKey + 'static,
+{
+    fn form_link(&self) -> &FormFieldLink<Key> {
+        &self.form_link
+    }
+    fn field_key(&self) -> &Key {
+        &self.field_key
+    }
+    fn extra_errors(&self) -> &ValidationErrors<Key> {
+        &self.extra_errors
+    }
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &self.error_renderer
+    }
+}
+
+impl<Key> Component for TagsInputField<Key>
+where
+    Key: FieldKey + 'static,
+{
+    type Message = TagsInputFieldMsg<Key>;
+    type Properties = TagsInputFieldProps<Key>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let form_link = props.form_link.clone();
+
+        let field_link = TagsInputFieldLink {
+            field_key: props.field_key.clone(),
+            link: link.clone(),
+        };
+
+        form_link.register_field(Rc::new(field_link));
+
+        TagsInputField {
+            tags: props.tags.clone(),
+            pending: String::new(),
+            tag_errors: ValidationErrors::default(),
+            validation_errors: ValidationErrors::default(),
+            display_validation_errors: props.extra_errors.clone(),
+            validated: false,
+            ids: FieldIds::new(),
+            input_ref: NodeRef::default(),
+            props,
+            form_link,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            TagsInputFieldMsg::Input(text) => {
+                self.tag_errors = ValidationErrors::default();
+
+                // Commit everything before the last comma, which may
+                // contain multiple tags if text was pasted.
+                match text.rfind(',') {
+                    Some(last_comma) => {
+                        let previous_len = self.tags.len();
+                        let mut rejected = Vec::new();
+
+                        for tag in text[..last_comma].split(',') {
+                            if let Err(errors) = self.add_tag(tag) {
+                                self.tag_errors.extend(errors);
+                                rejected.push(tag.trim());
+                            }
+                        }
+
+                        rejected.push(text[(last_comma + 1)..].trim_start());
+                        rejected.retain(|tag| !tag.is_empty());
+                        self.pending = rejected.join(", ");
+
+                        if self.tags.len() != previous_len {
+                            self.tags_updated();
+                        }
+                    }
+                    None => self.pending = text,
+                }
+                true
+            }
+            TagsInputFieldMsg::Commit => {
+                let pending = std::mem::take(&mut self.pending);
+                let previous_len = self.tags.len();
+
+                match self.add_tag(&pending) {
+                    Ok(()) => self.tag_errors = ValidationErrors::default(),
+                    Err(errors) => {
+                        self.tag_errors = errors;
+                        self.pending = pending;
+                    }
+                }
+
+                if self.tags.len() != previous_len {
+                    self.tags_updated();
+                }
+                true
+            }
+            TagsInputFieldMsg::RemoveTag(index) => {
+                if index < self.tags.len() {
+                    self.tags.remove(index);
+                    self.tags_updated();
+                    true
+                } else {
+                    false
+                }
+            }
+            TagsInputFieldMsg::RemoveLastTag => {
+                if self.tags.pop().is_some() {
+                    self.tags_updated();
+                    true
+                } else {
+                    false
+                }
+            }
+            TagsInputFieldMsg::Validate => {
+                let validate_future = self.validate_future_or_empty();
+                self.link.send_future(async move {
+                    let validation_errors = validate_future.await;
+
+                    TagsInputFieldMsg::ValidationErrors(validation_errors)
+                });
+                false
+            }
+            TagsInputFieldMsg::ValidationErrors(errors) => {
+                self.validation_errors = errors.clone();
+                self.validated = true;
+
+                let mut display_errors = errors;
+                display_errors.extend(self.props.extra_errors.clone());
+                self.display_validation_errors = display_errors;
+
+                self.form_link
+                    .send_form_message(FormMsg::FieldValidationUpdate(
+                        self.props.field_key.clone(),
+                        self.validation_errors.clone(),
+                    ));
+                true
+            }
+            TagsInputFieldMsg::ClearValidationErrors => {
+                self.validation_errors = ValidationErrors::default();
+                self.tag_errors = ValidationErrors::default();
+                self.validated = false;
+                self.display_validation_errors = self.props.extra_errors.clone();
+
+                self.form_link
+                    .send_form_message(FormMsg::FieldValidationUpdate(
+                        self.props.field_key.clone(),
+                        self.validation_errors.clone(),
+                    ));
+                true
+            }
+            TagsInputFieldMsg::Focus => {
+                focus_node(&self.input_ref);
+                false
+            }
+            TagsInputFieldMsg::Rerender => true,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let link = self.link.clone();
+
+        if self.props.extra_errors != props.extra_errors {
+            let mut errors = self.validation_errors.clone();
+            errors.extend(props.extra_errors.clone());
+            self.display_validation_errors = errors;
+        }
+
+        self.props.neq_assign_field(props, move |new_props| {
+            Rc::new(TagsInputFieldLink {
+                field_key: new_props.field_key().clone(),
+                link: link.clone(),
+            })
+        })
+    }
+
+    fn view(&self) -> Html {
+        let mut input_classes = vec!["input".to_string()];
+
+        let mut errors = self
+            .display_validation_errors
+            .get(&self.props.field_key)
+            .unwrap_or_default();
+        errors.extend(self.tag_errors.clone());
+        let has_errors = !errors.is_empty();
+
+        let validation_error = if has_errors {
+            input_classes.push("is-danger".to_string());
+            errors_view(&self.ids, self.props.render_errors(&errors))
+        } else {
+            html! {}
+        };
+
+        let success = self.success();
+        let described_by = self.ids.described_by(
+            has_errors,
+            success && self.props.success_help.is_some(),
+            self.props.help.is_some(),
+        );
+
+        if success {
+            input_classes.push("is-success".to_string());
+        }
+
+        let oninput = self
+            .link
+            .callback(|data: InputData| TagsInputFieldMsg::Input(data.value));
+        let pending_empty = self.pending.is_empty();
+        let onkeydown =
+            self.link
+                .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                    // Prevent the form from being submitted when
+                    // committing a tag.
+                    "Enter" => {
+                        event.prevent_default();
+                        Some(TagsInputFieldMsg::Commit)
+                    }
+                    "Backspace" if pending_empty => Some(TagsInputFieldMsg::RemoveLastTag),
+                    _ => None,
+                });
+
+        let placeholder = match &self.props.placeholder {
+            Placeholder::FieldKey => self.props.field_key.to_string(),
+            Placeholder::Text(text) => text.clone(),
+            Placeholder::None => String::new(),
+        };
+
+        let tags: Html = self
+            .tags
+            .iter()
+            .enumerate()
+            .map(|(index, tag)| self.view_tag(index, tag))
+            .collect();
+
+        html! {
+            <div class="field">
+                {
+                    match self.label() {
+                        Some(label) => {
+                            html!{
+                                <label class="label" for=self.ids.control()>{ label }</label>
+                            }
+                        },
+                        None => {
+                            html!{}
+                        }
+                    }
+                }
+                <div class="field is-grouped is-grouped-multiline">
+                    { tags }
+                    <div class="control is-expanded">
+                        <input
+                            ref=self.input_ref.clone()
+                            id=self.ids.control()
+                            class=input_classes
+                            type="text"
+                            value=self.pending.clone()
+                            placeholder=placeholder
+                            oninput=oninput
+                            onkeydown=onkeydown
+                            disabled=self.props.disabled
+                            aria-invalid=has_errors.then_some("true")
+                            aria-required=self.props.required.then_some("true")
+                            aria-describedby=described_by/>
+                    </div>
+                </div>
+                { validation_error }
+                { success_help_view(&self.ids, success, &self.props.success_help) }
+                { help_view(&self.ids, &self.props.help) }
+            </div>
+        }
+    }
+}
+
+impl<Key> AsyncValidatable<Key> for TagsInputField<Key>
+where
+    Key: FieldKey + 'static,
+{
+    fn validate_future(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>> {
+        let value = self.tags.clone();
+        let field_key = self.props.field_key.clone();
+        let validator = self.props.validator.clone();
+        Box::pin(async move { validator.validate_value(&value, &field_key).await })
+    }
+}
+
+impl<Key> FormField<Key> for TagsInputField<Key>
+where
+    Key: FieldKey + 'static,
+{
+    fn validation_errors(&self) -> &ValidationErrors<Key> {
+        &self.validation_errors
+    }
+
+    fn field_key(&self) -> &Key {
+        &self.props.field_key
+    }
+}
@@ -0,0 +1,798 @@
+//! A form field for choosing a value from a potentially large set of
+//! suggestions, which are provided asynchronously (for example from
+//! a server) as the user types. The suggestions are displayed in a
+//! `bulma`
+//! [dropdown](https://bulma.io/documentation/components/dropdown/)
+//! beneath the field's `<input>`.
+
+use super::{
+    focus::focus_node,
+    help::{errors_view, help_view, success_help_view},
+    input_field::{Label, Placeholder},
+    FieldIds, FieldKey, FieldLink, FieldMsg, FieldProps, FormField, FormFieldLink, FormMsg,
+    NeqAssignFieldProps, SharedErrorRenderer,
+};
+
+use form_validation::{AsyncValidatable, AsyncValidator, ValidationErrors};
+use web_sys::KeyboardEvent;
+use yew::{
+    html,
+    services::{timeout::TimeoutTask, TimeoutService},
+    Callback, Component, ComponentLink, Html, InputData, NodeRef, Properties, ShouldRender,
+};
+use yewtil::future::LinkFuture;
+
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    time::Duration,
+};
+
+type SuggestionsFuture<Value> = Pin<Box<dyn Future<Output = Vec<Value>>>>;
+
+/// An asynchronous function which produces the suggestions for a
+/// [TypeaheadField], given the text that the user has typed into the
+/// field.
+///
+/// Each `SuggestionsFn` has a `key` which identifies the suggestions
+/// it produces, and functions are compared by their key. This means
+/// the function can be created inline in the parent's `view()`
+/// without the field being updated on every render. If the function
+/// captures state which changes its suggestions (for example a
+/// selected category), include that state in the `key`.
+pub struct SuggestionsFn<Value> {
+    key: String,
+    function: Rc<dyn Fn(String) -> SuggestionsFuture<Value>>,
+}
+
+impl<Value> SuggestionsFn<Value> {
+    /// Create a new `SuggestionsFn`, identified by `key`.
+    pub fn new<K, F, Fut>(key: K, function: F) -> Self
+    where
+        K: Into<String>,
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<Value>> + 'static,
+    {
+        Self {
+            key: key.into(),
+            function: Rc::new(move |query| Box::pin(function(query))),
+        }
+    }
+
+    /// The key which identifies the suggestions this function
+    /// produces.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Produce the suggestions for the specified `query`.
+    pub fn suggestions(&self, query: String) -> SuggestionsFuture<Value> {
+        (self.function)(query)
+    }
+}
+
+impl<Value> Clone for SuggestionsFn<Value> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            function: Rc::clone(&self.function),
+        }
+    }
+}
+
+impl<Value> PartialEq for SuggestionsFn<Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<Value> Debug for SuggestionsFn<Value> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SuggestionsFn(key: {:?})", self.key)
+    }
+}
+
+/// A form field which allows the user to choose a `Value` from
+/// suggestions which are fetched asynchronously using
+/// [TypeaheadFieldProps::suggestions] as the user types.
+///
+/// The field's value is `None` until the user chooses one of the
+/// suggestions, either by clicking on it, or by navigating to it with
+/// the arrow keys and pressing `Enter`.
+#[derive(Debug)]
+pub struct TypeaheadField<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    value: Option<Value>,
+    /// The text currently in the `<input>`.
+    query: String,
+    suggestions: Vec<Value>,
+    /// Index into `suggestions` of the suggestion which is currently
+    /// highlighted using the keyboard.
+    highlighted: Option<usize>,
+    /// Whether the suggestions dropdown is open.
+    active: bool,
+    /// Whether suggestions are currently being fetched.
+    loading: bool,
+    /// Incremented for each new query, to identify and ignore
+    /// suggestions which arrive for an outdated query.
+    query_id: u64,
+    /// Timer used to debounce fetching the suggestions.
+    debounce_task: Option<TimeoutTask>,
+    validation_errors: ValidationErrors<Key>,
+    display_validation_errors: ValidationErrors<Key>,
+    /// Whether a validation has completed since the value was last
+    /// updated, or the validation errors were last cleared.
+    validated: bool,
+    ids: FieldIds,
+    input_ref: NodeRef,
+    props: TypeaheadFieldProps<Value, Key>,
+    form_link: FormFieldLink<Key>,
+    link: ComponentLink<Self>,
+}
+
+impl<Value, Key> TypeaheadField<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    fn label(&self) -> Option<String> {
+        match &self.props.label {
+            Label::FieldKey => Some(self.props.field_key.to_string()),
+            Label::Text(text) => Some(text.clone()),
+            Label::None => None,
+        }
+    }
+
+    /// Whether this field should currently be displayed with the
+    /// `is-success` style.
+    fn success(&self) -> bool {
+        self.props.show_success
+            && self.validated
+            && self
+                .display_validation_errors
+                .get(&self.props.field_key)
+                .is_none()
+    }
+
+    /// Whether the suggestions dropdown is currently displayed.
+    fn dropdown_active(&self) -> bool {
+        self.active && !self.suggestions.is_empty()
+    }
+
+    /// Cancel any pending request for suggestions.
+    fn cancel_suggestions(&mut self) {
+        self.query_id += 1;
+        self.debounce_task = None;
+        self.loading = false;
+    }
+
+    /// Set the value of the field, notifying the form and validating
+    /// if required.
+    fn set_value(&mut self, value: Option<Value>) {
+        if self.replace_value(value) && self.props.validate_on_update {
+            self.update(TypeaheadFieldMsg::Validate);
+        }
+    }
+
+    /// Update the value and notify the parent and the form, without
+    /// validating it. Returns whether the value changed.
+    fn replace_value(&mut self, value: Option<Value>) -> bool {
+        if self.value == value {
+            return false;
+        }
+
+        self.value = value.clone();
+        self.validated = false;
+        self.props.onupdate.emit(value);
+        self.form_link
+            .send_form_message(FormMsg::FieldValueUpdate(self.props.field_key.clone()));
+        true
+    }
+
+    /// Replace the selection and the text in the `<input>` with the
+    /// `selected` property, after the parent has changed it.
+    fn sync_selected(&mut self) {
+        self.cancel_suggestions();
+        self.active = false;
+        self.highlighted = None;
+        self.value = self.props.selected.clone();
+        self.query = self
+            .value
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        self.validated = false;
+        self.form_link
+            .send_form_message(FormMsg::FieldValueUpdate(self.props.field_key.clone()));
+
+        if self.props.validate_on_update {
+            self.update(TypeaheadFieldMsg::Validate);
+        }
+    }
+
+    fn view_suggestion(&self, index: usize, value: &Value) -> Html {
+        let mut classes = vec!["dropdown-item".to_string()];
+        let highlighted = self.highlighted == Some(index);
+
+        if highlighted {
+            classes.push("is-active".to_string());
+        }
+
+        // `onmousedown` is used instead of `onclick` so that the
+        // suggestion is selected before the `<input>` loses focus
+        // and closes the dropdown.
+        let onmousedown = self.link.callback(move |event: web_sys::MouseEvent| {
+            event.prevent_default();
+            TypeaheadFieldMsg::Select(index)
+        });
+
+        html! {
+            <a
+                class=classes
+                id=self.ids.option(index)
+                role="option"
+                aria-selected=highlighted.to_string()
+                onmousedown=onmousedown>
+                { value.to_string() }
+            </a>
+        }
+    }
+}
+
+pub enum TypeaheadFieldMsg<Value, Key> {
+    /// The user has typed into the `<input>`.
+    Input(String),
+    /// The debounce timer for the query with the specified id has
+    /// elapsed, fetch the suggestions.
+    FetchSuggestions(u64),
+    /// Suggestions have arrived for the query with the specified id.
+    Suggestions(u64, Vec<Value>),
+    /// Highlight the next suggestion.
+    HighlightNext,
+    /// Highlight the previous suggestion.
+    HighlightPrevious,
+    /// Select the highlighted suggestion.
+    SelectHighlighted,
+    /// Select the suggestion with the specified index.
+    Select(usize),
+    /// Close the suggestions dropdown.
+    Close,
+    /// The `<input>` has lost focus.
+    Blur,
+    /// Validate this field, sends a [FormMsg::FieldValidationUpdate]
+    /// to the `form_link` upon completion.
+    Validate,
+    ValidationErrors(ValidationErrors<Key>),
+    ClearValidationErrors,
+    /// Move the browser's focus to the `<input>` element.
+    Focus,
+    /// Re-render the field, because the [Form](super::Form)'s
+    /// shared configuration (such as its error renderer) has changed.
+    Rerender,
+}
+
+impl<Value, Key> From<FieldMsg> for TypeaheadFieldMsg<Value, Key> {
+    fn from(msg: FieldMsg) -> Self {
+        match msg {
+            FieldMsg::Validate => TypeaheadFieldMsg::Validate,
+            FieldMsg::ClearValidationErrors => TypeaheadFieldMsg::ClearValidationErrors,
+            FieldMsg::Focus => TypeaheadFieldMsg::Focus,
+            FieldMsg::Rerender => TypeaheadFieldMsg::Rerender,
+        }
+    }
+}
+
+pub struct TypeaheadFieldLink<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    pub field_key: Key,
+    pub link: ComponentLink<TypeaheadField<Value, Key>>,
+}
+
+impl<Value, Key> Debug for TypeaheadFieldLink<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TypeaheadFieldLink<{0:?}>", self.field_key())
+    }
+}
+
+impl<Value, Key> FieldLink<Key> for TypeaheadFieldLink<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    fn field_key(&self) -> &Key {
+        &self.field_key
+    }
+    fn send_message(&self, msg: FieldMsg) {
+        self.link.send_message(msg)
+    }
+}
+
+/// [Properties](yew::Component::Properties) for [TypeaheadField].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct TypeaheadFieldProps<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq,
+{
+    /// The key used to refer to this field.
+    pub field_key: Key,
+    /// The link to the form that this field belongs to.
+    pub form_link: FormFieldLink<Key>,
+    /// The function used to fetch suggestions for the text that the
+    /// user has typed.
+    pub suggestions: SuggestionsFn<Value>,
+    /// (Optional) How long to wait after the user stops typing before
+    /// fetching suggestions. By default this is 300 milliseconds.
+    #[prop_or(Duration::from_millis(300))]
+    pub debounce: Duration,
+    /// (Optional) The minimum number of characters that need to be
+    /// typed before suggestions are fetched. By default this is `1`.
+    #[prop_or(1)]
+    pub min_query_length: usize,
+    /// Whether or not the input for this field is disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// (Optional) Set the label text. By default this is
+    /// [Label::FieldKey].
+    #[prop_or_default]
+    pub label: Label,
+    /// (Optional) Placeholder text. By default this is
+    /// [Placeholder::None].
+    #[prop_or_default]
+    pub placeholder: Placeholder,
+    /// (Optional) The selected value. When this changes, the
+    /// selection and the text in the `<input>` are replaced with the
+    /// new value.
+    #[prop_or_default]
+    pub selected: Option<Value>,
+    /// (Optional) What validator to use for this field.
+    #[prop_or_default]
+    pub validator: AsyncValidator<Option<Value>, Key>,
+    /// (Optional) A callback for when the value of this field
+    /// changes.
+    #[prop_or_default]
+    pub onupdate: Callback<Option<Value>>,
+    /// (Optional) Whether to validate when the field is updated.
+    #[prop_or(true)]
+    pub validate_on_update: bool,
+    /// (Optional) Extra validation errors to display. These errors
+    /// are not reported to the `Form`.
+    #[prop_or_default]
+    pub extra_errors: ValidationErrors<Key>,
+    /// (Optional) Override the [ErrorRenderer](super::ErrorRenderer)
+    /// provided by the `Form` for rendering this field's validation
+    /// errors.
+    #[prop_or_default]
+    pub error_renderer: Option<SharedErrorRenderer<Key>>,
+    /// (Optional) Whether this field is required, which is
+    /// announced to assistive technologies with the `aria-required`
+    /// attribute. This does not perform any validation, see
+    /// `validator`. By default this is `false`.
+    #[prop_or(false)]
+    pub required: bool,
+    /// (Optional) Help text to display beneath the field.
+    #[prop_or_default]
+    pub help: Option<String>,
+    /// (Optional) Whether to apply the `is-success` style to this
+    /// field once a validation has completed without any errors. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub show_success: bool,
+    /// (Optional) Help text to display beneath the field when
+    /// `show_success` is `true`, and a validation has completed
+    /// without any errors.
+    #[prop_or_default]
+    pub success_help: Option<String>,
+}
+
+impl<Value, Key> FieldProps<Key> for TypeaheadFieldProps<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq,
+{
+    fn form_link(&self) -> &FormFieldLink<Key> {
+        &self.form_link
+    }
+    fn field_key(&self) -> &Key {
+        &self.field_key
+    }
+    fn extra_errors(&self) -> &ValidationErrors<Key> {
+        &self.extra_errors
+    }
+    fn error_renderer(&self) -> &Option<SharedErrorRenderer<Key>> {
+        &self.error_renderer
+    }
+}
+
+impl<Value, Key> Component for TypeaheadField<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    type Message = TypeaheadFieldMsg<Value, Key>;
+    type Properties = TypeaheadFieldProps<Value, Key>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let form_link = props.form_link.clone();
+
+        let field_link = TypeaheadFieldLink {
+            field_key: props.field_key.clone(),
+            link: link.clone(),
+        };
+
+        form_link.register_field(Rc::new(field_link));
+
+        TypeaheadField {
+            value: props.selected.clone(),
+            query: props
+                .selected
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            suggestions: Vec::new(),
+            highlighted: None,
+            active: false,
+            loading: false,
+            query_id: 0,
+            debounce_task: None,
+            validation_errors: ValidationErrors::default(),
+            display_validation_errors: props.extra_errors.clone(),
+            validated: false,
+            ids: FieldIds::new(),
+            input_ref: NodeRef::default(),
+            props,
+            form_link,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            TypeaheadFieldMsg::Input(query) => {
+                self.query = query;
+                self.highlighted = None;
+                self.cancel_suggestions();
+
+                // The text no longer matches the selected value. This
+                // isn't validated, so that a "required" error isn't
+                // displayed while the user is typing a new query.
+                self.replace_value(None);
+
+                if self.query.chars().count() >= self.props.min_query_length {
+                    let query_id = self.query_id;
+                    self.debounce_task = Some(TimeoutService::spawn(
+                        self.props.debounce,
+                        self.link
+                            .callback(move |_| TypeaheadFieldMsg::FetchSuggestions(query_id)),
+                    ));
+                } else {
+                    self.suggestions.clear();
+                    self.active = false;
+                }
+                true
+            }
+            TypeaheadFieldMsg::FetchSuggestions(query_id) => {
+                if query_id != self.query_id {
+                    return false;
+                }
+
+                self.debounce_task = None;
+                self.loading = true;
+
+                let suggestions = self.props.suggestions.suggestions(self.query.clone());
+                self.link.send_future(async move {
+                    TypeaheadFieldMsg::Suggestions(query_id, suggestions.await)
+                });
+                true
+            }
+            TypeaheadFieldMsg::Suggestions(query_id, suggestions) => {
+                if query_id != self.query_id {
+                    return false;
+                }
+
+                self.loading = false;
+                self.suggestions = suggestions;
+                self.highlighted = None;
+                self.active = true;
+                true
+            }
+            TypeaheadFieldMsg::HighlightNext => {
+                if self.suggestions.is_empty() {
+                    return false;
+                }
+
+                if !self.active {
+                    self.active = true;
+                    return true;
+                }
+
+                self.highlighted = match self.highlighted {
+                    Some(index) if index + 1 < self.suggestions.len() => Some(index + 1),
+                    Some(index) => Some(index),
+                    None => Some(0),
+                };
+                true
+            }
+            TypeaheadFieldMsg::HighlightPrevious => {
+                if !self.dropdown_active() {
+                    return false;
+                }
+
+                self.highlighted = match self.highlighted {
+                    Some(index) if index > 0 => Some(index - 1),
+                    _ => None,
+                };
+                true
+            }
+            TypeaheadFieldMsg::SelectHighlighted => match self.highlighted {
+                Some(index) => self.update(TypeaheadFieldMsg::Select(index)),
+                None => false,
+            },
+            TypeaheadFieldMsg::Select(index) => {
+                let value = match self.suggestions.get(index) {
+                    Some(value) => value.clone(),
+                    None => return false,
+                };
+
+                self.cancel_suggestions();
+                self.query = value.to_string();
+                self.active = false;
+                self.highlighted = None;
+                self.set_value(Some(value));
+                true
+            }
+            TypeaheadFieldMsg::Close => {
+                self.active = false;
+                self.highlighted = None;
+                true
+            }
+            TypeaheadFieldMsg::Blur => {
+                self.cancel_suggestions();
+                self.active = false;
+                self.highlighted = None;
+
+                if let Some(value) = &self.value {
+                    self.query = value.to_string();
+                }
+                true
+            }
+            TypeaheadFieldMsg::Validate => {
+                let validate_future = self.validate_future_or_empty();
+                self.link.send_future(async move {
+                    let validation_errors = validate_future.await;
+
+                    TypeaheadFieldMsg::ValidationErrors(validation_errors)
+                });
+                false
+            }
+            TypeaheadFieldMsg::ValidationErrors(errors) => {
+                self.validation_errors = errors.clone();
+                self.validated = true;
+
+                let mut display_errors = errors;
+                display_errors.extend(self.props.extra_errors.clone());
+                self.display_validation_errors = display_errors;
+
+                self.form_link
+                    .send_form_message(FormMsg::FieldValidationUpdate(
+                        self.props.field_key.clone(),
+                        self.validation_errors.clone(),
+                    ));
+                true
+            }
+            TypeaheadFieldMsg::ClearValidationErrors => {
+                self.validation_errors = ValidationErrors::default();
+                self.validated = false;
+                self.display_validation_errors = self.props.extra_errors.clone();
+
+                self.form_link
+                    .send_form_message(FormMsg::FieldValidationUpdate(
+                        self.props.field_key.clone(),
+                        self.validation_errors.clone(),
+                    ));
+                true
+            }
+            TypeaheadFieldMsg::Focus => {
+                focus_node(&self.input_ref);
+                false
+            }
+            TypeaheadFieldMsg::Rerender => true,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let link = self.link.clone();
+
+        if self.props.extra_errors != props.extra_errors {
+            let mut errors = self.validation_errors.clone();
+            errors.extend(props.extra_errors.clone());
+            self.display_validation_errors = errors;
+        }
+
+        let selected_changed = self.props.selected != props.selected;
+
+        let render = self.props.neq_assign_field(props, move |new_props| {
+            Rc::new(TypeaheadFieldLink {
+                field_key: new_props.field_key().clone(),
+                link: link.clone(),
+            })
+        });
+
+        if selected_changed && self.value != self.props.selected {
+            self.sync_selected();
+        }
+
+        render
+    }
+
+    fn view(&self) -> Html {
+        let mut input_classes = vec!["input".to_string()];
+
+        let errors = self.display_validation_errors.get(&self.props.field_key);
+        let has_errors = errors.is_some();
+
+        let validation_error = if let Some(errors) = errors {
+            input_classes.push("is-danger".to_string());
+            errors_view(&self.ids, self.props.render_errors(&errors))
+        } else {
+            html! {}
+        };
+
+        let success = self.success();
+        let described_by = self.ids.described_by(
+            has_errors,
+            success && self.props.success_help.is_some(),
+            self.props.help.is_some(),
+        );
+
+        if success {
+            input_classes.push("is-success".to_string());
+        }
+
+        let dropdown_active = self.dropdown_active();
+
+        let mut dropdown_classes = vec!["dropdown".to_string()];
+        if dropdown_active {
+            dropdown_classes.push("is-active".to_string());
+        }
+
+        let mut control_classes = vec!["control".to_string()];
+        if self.loading {
+            control_classes.push("is-loading".to_string());
+        }
+
+        let oninput = self
+            .link
+            .callback(|data: InputData| TypeaheadFieldMsg::Input(data.value));
+        let onblur = self.link.callback(|_| TypeaheadFieldMsg::Blur);
+        let onkeydown =
+            self.link
+                .batch_callback(move |event: KeyboardEvent| match event.key().as_str() {
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        Some(TypeaheadFieldMsg::HighlightNext)
+                    }
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        Some(TypeaheadFieldMsg::HighlightPrevious)
+                    }
+                    // Prevent the form from being submitted while choosing
+                    // a suggestion.
+                    "Enter" if dropdown_active => {
+                        event.prevent_default();
+                        Some(TypeaheadFieldMsg::SelectHighlighted)
+                    }
+                    "Escape" if dropdown_active => Some(TypeaheadFieldMsg::Close),
+                    _ => None,
+                });
+
+        let placeholder = match &self.props.placeholder {
+            Placeholder::FieldKey => self.props.field_key.to_string(),
+            Placeholder::Text(text) => text.clone(),
+            Placeholder::None => String::new(),
+        };
+
+        let menu_id = format!("{}-menu", self.ids.control());
+        let active_descendant = match self.highlighted {
+            Some(index) if dropdown_active => Some(self.ids.option(index)),
+            _ => None,
+        };
+
+        let suggestions: Html = self
+            .suggestions
+            .iter()
+            .enumerate()
+            .map(|(index, value)| self.view_suggestion(index, value))
+            .collect();
+
+        html! {
+            <div class="field">
+                {
+                    match self.label() {
+                        Some(label) => {
+                            html!{
+                                <label class="label" for=self.ids.control()>{ label }</label>
+                            }
+                        },
+                        None => {
+                            html!{}
+                        }
+                    }
+                }
+                <div class=dropdown_classes>
+                    <div class="dropdown-trigger">
+                        <div class=control_classes>
+                            <input
+                                ref=self.input_ref.clone()
+                                id=self.ids.control()
+                                class=input_classes
+                                type="text"
+                                autocomplete="off"
+                                value=self.query.clone()
+                                placeholder=placeholder
+                                oninput=oninput
+                                onkeydown=onkeydown
+                                onblur=onblur
+                                disabled=self.props.disabled
+                                role="combobox"
+                                aria-autocomplete="list"
+                                aria-expanded=dropdown_active.to_string()
+                                aria-controls=menu_id.clone()
+                                aria-activedescendant=active_descendant
+                                aria-invalid=has_errors.then_some("true")
+                                aria-required=self.props.required.then_some("true")
+                                aria-describedby=described_by/>
+                        </div>
+                    </div>
+                    <div class="dropdown-menu" id=menu_id role="listbox">
+                        <div class="dropdown-content">
+                            { suggestions }
+                        </div>
+                    </div>
+                </div>
+                { validation_error }
+                { success_help_view(&self.ids, success, &self.props.success_help) }
+                { help_view(&self.ids, &self.props.help) }
+            </div>
+        }
+    }
+}
+
+impl<Value, Key> AsyncValidatable<Key> for TypeaheadField<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    fn validate_future(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors<Key>>>>> {
+        let value = self.value.clone();
+        let field_key = self.props.field_key.clone();
+        let validator = self.props.validator.clone();
+        Box::pin(async move { validator.validate_value(&value, &field_key).await })
+    }
+}
+
+impl<Value, Key> FormField<Key> for TypeaheadField<Value, Key>
+where
+    Key: FieldKey + 'static,
+    Value: Clone + PartialEq + Display + Debug + 'static,
+{
+    fn validation_errors(&self) -> &ValidationErrors<Key> {
+        &self.validation_errors
+    }
+
+    fn field_key(&self) -> &Key {
+        &self.props.field_key
+    }
+}
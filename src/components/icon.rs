@@ -1,31 +1,563 @@
-//! A `yew` [Component](yew::Component) to render a `bulma`
-//! [icon](https://bulma.io/documentation/elements/icon/).
+//! `yew` [Component](yew::Component)s to render a `bulma`
+//! [icon](https://bulma.io/documentation/elements/icon/), and an
+//! [icon-text](https://bulma.io/documentation/elements/icon/#icon-text)
+//! which pairs an icon with a label.
+//!
+//! The icon itself is described by an [IconSource], which can be an
+//! icon from any [IconSet] (such as the built-in [FontAwesome] 5 and
+//! [MaterialDesignIcons] sets, or a set implemented by the app), raw
+//! classes for any other icon font, or inline SVG markup for apps
+//! which can't load an icon font.
 
-use crate::classes::Color;
-use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use crate::classes::{Color, IconSize};
+
+use std::{any::Any, fmt::Debug, marker::PhantomData, rc::Rc};
+use web_sys::Element;
+use yew::{
+    html, utils::document, Children, Component, ComponentLink, Html, Properties, ShouldRender,
+};
 use yewtil::NeqAssign;
 
+/// A set of icons displayed using an icon font. Implement this for
+/// an icon font which isn't built in, and display its icons with
+/// [IconSource::from_set].
+pub trait IconSet: 'static {
+    /// The name of an icon in this set.
+    type Name: Clone + PartialEq + Debug + 'static;
+
+    /// The classes of the `<i>` element which displays the icon
+    /// `name`, filling an `icon` container of the specified `size`.
+    fn classes(name: &Self::Name, size: IconSize) -> Vec<String>;
+}
+
+/// The style of a [FontAwesomeIcon], which determines which of the
+/// Font Awesome 5 fonts it is displayed with.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum FontAwesomeStyle {
+    #[default]
+    Solid,
+    Regular,
+    /// Only available with Font Awesome Pro.
+    Light,
+    /// Only available with Font Awesome Pro.
+    Duotone,
+    Brands,
+}
+
+impl FontAwesomeStyle {
+    pub fn to_class(&self) -> String {
+        match self {
+            FontAwesomeStyle::Solid => "fas".to_string(),
+            FontAwesomeStyle::Regular => "far".to_string(),
+            FontAwesomeStyle::Light => "fal".to_string(),
+            FontAwesomeStyle::Duotone => "fad".to_string(),
+            FontAwesomeStyle::Brands => "fab".to_string(),
+        }
+    }
+}
+
+/// The name of a [Font Awesome 5](https://fontawesome.com/v5/search)
+/// icon. Commonly used icons have their own variant, any other icon
+/// can be named with [FontAwesomeName::Other].
+#[derive(PartialEq, Clone, Debug)]
+pub enum FontAwesomeName {
+    AngleDown,
+    AngleLeft,
+    AngleRight,
+    AngleUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    Bars,
+    Bell,
+    Calendar,
+    Check,
+    CheckCircle,
+    ChevronDown,
+    ChevronLeft,
+    ChevronRight,
+    ChevronUp,
+    Cog,
+    Download,
+    Edit,
+    Envelope,
+    ExclamationCircle,
+    ExclamationTriangle,
+    Eye,
+    EyeSlash,
+    Heart,
+    Home,
+    InfoCircle,
+    Lock,
+    Minus,
+    Plus,
+    Search,
+    SignOutAlt,
+    Spinner,
+    Star,
+    Times,
+    Trash,
+    Upload,
+    User,
+    /// Any other icon, named without the `fa-` prefix, for example
+    /// `"coffee"`.
+    Other(String),
+}
+
+impl FontAwesomeName {
+    /// The name of the icon without the `fa-` prefix.
+    pub fn to_str(&self) -> &str {
+        match self {
+            FontAwesomeName::AngleDown => "angle-down",
+            FontAwesomeName::AngleLeft => "angle-left",
+            FontAwesomeName::AngleRight => "angle-right",
+            FontAwesomeName::AngleUp => "angle-up",
+            FontAwesomeName::ArrowDown => "arrow-down",
+            FontAwesomeName::ArrowLeft => "arrow-left",
+            FontAwesomeName::ArrowRight => "arrow-right",
+            FontAwesomeName::ArrowUp => "arrow-up",
+            FontAwesomeName::Bars => "bars",
+            FontAwesomeName::Bell => "bell",
+            FontAwesomeName::Calendar => "calendar",
+            FontAwesomeName::Check => "check",
+            FontAwesomeName::CheckCircle => "check-circle",
+            FontAwesomeName::ChevronDown => "chevron-down",
+            FontAwesomeName::ChevronLeft => "chevron-left",
+            FontAwesomeName::ChevronRight => "chevron-right",
+            FontAwesomeName::ChevronUp => "chevron-up",
+            FontAwesomeName::Cog => "cog",
+            FontAwesomeName::Download => "download",
+            FontAwesomeName::Edit => "edit",
+            FontAwesomeName::Envelope => "envelope",
+            FontAwesomeName::ExclamationCircle => "exclamation-circle",
+            FontAwesomeName::ExclamationTriangle => "exclamation-triangle",
+            FontAwesomeName::Eye => "eye",
+            FontAwesomeName::EyeSlash => "eye-slash",
+            FontAwesomeName::Heart => "heart",
+            FontAwesomeName::Home => "home",
+            FontAwesomeName::InfoCircle => "info-circle",
+            FontAwesomeName::Lock => "lock",
+            FontAwesomeName::Minus => "minus",
+            FontAwesomeName::Plus => "plus",
+            FontAwesomeName::Search => "search",
+            FontAwesomeName::SignOutAlt => "sign-out-alt",
+            FontAwesomeName::Spinner => "spinner",
+            FontAwesomeName::Star => "star",
+            FontAwesomeName::Times => "times",
+            FontAwesomeName::Trash => "trash",
+            FontAwesomeName::Upload => "upload",
+            FontAwesomeName::User => "user",
+            FontAwesomeName::Other(name) => name,
+        }
+    }
+}
+
+/// A [Font Awesome 5](https://fontawesome.com/v5/search) icon, the
+/// [IconSet::Name] of the [FontAwesome] set.
+#[derive(PartialEq, Clone, Debug)]
+pub struct FontAwesomeIcon {
+    pub style: FontAwesomeStyle,
+    pub name: FontAwesomeName,
+}
+
+impl FontAwesomeIcon {
+    pub fn new(style: FontAwesomeStyle, name: FontAwesomeName) -> Self {
+        Self { style, name }
+    }
+
+    pub fn solid(name: FontAwesomeName) -> Self {
+        Self::new(FontAwesomeStyle::Solid, name)
+    }
+
+    pub fn regular(name: FontAwesomeName) -> Self {
+        Self::new(FontAwesomeStyle::Regular, name)
+    }
+
+    pub fn brands(name: FontAwesomeName) -> Self {
+        Self::new(FontAwesomeStyle::Brands, name)
+    }
+}
+
+/// The [Font Awesome 5](https://fontawesome.com/) [IconSet].
+#[derive(Debug)]
+pub struct FontAwesome;
+
+impl IconSet for FontAwesome {
+    type Name = FontAwesomeIcon;
+
+    fn classes(name: &Self::Name, size: IconSize) -> Vec<String> {
+        let mut classes = vec![name.style.to_class(), format!("fa-{}", name.name.to_str())];
+        classes.extend(size.font_awesome_class());
+        classes
+    }
+}
+
+/// The name of a
+/// [Material Design Icons](https://materialdesignicons.com/) icon,
+/// the [IconSet::Name] of the [MaterialDesignIcons] set. Commonly
+/// used icons have their own variant, any other icon can be named
+/// with [MaterialDesignIcon::Other].
+#[derive(PartialEq, Clone, Debug)]
+pub enum MaterialDesignIcon {
+    Account,
+    Alert,
+    AlertCircle,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    Bell,
+    Calendar,
+    Check,
+    CheckCircle,
+    ChevronDown,
+    ChevronLeft,
+    ChevronRight,
+    ChevronUp,
+    Close,
+    Cog,
+    Delete,
+    Download,
+    Email,
+    Eye,
+    EyeOff,
+    Heart,
+    Home,
+    Information,
+    Loading,
+    Lock,
+    Logout,
+    Magnify,
+    Menu,
+    Minus,
+    Pencil,
+    Plus,
+    Star,
+    Upload,
+    /// Any other icon, named without the `mdi-` prefix, for example
+    /// `"coffee"`.
+    Other(String),
+}
+
+impl MaterialDesignIcon {
+    /// The name of the icon without the `mdi-` prefix.
+    pub fn to_str(&self) -> &str {
+        match self {
+            MaterialDesignIcon::Account => "account",
+            MaterialDesignIcon::Alert => "alert",
+            MaterialDesignIcon::AlertCircle => "alert-circle",
+            MaterialDesignIcon::ArrowDown => "arrow-down",
+            MaterialDesignIcon::ArrowLeft => "arrow-left",
+            MaterialDesignIcon::ArrowRight => "arrow-right",
+            MaterialDesignIcon::ArrowUp => "arrow-up",
+            MaterialDesignIcon::Bell => "bell",
+            MaterialDesignIcon::Calendar => "calendar",
+            MaterialDesignIcon::Check => "check",
+            MaterialDesignIcon::CheckCircle => "check-circle",
+            MaterialDesignIcon::ChevronDown => "chevron-down",
+            MaterialDesignIcon::ChevronLeft => "chevron-left",
+            MaterialDesignIcon::ChevronRight => "chevron-right",
+            MaterialDesignIcon::ChevronUp => "chevron-up",
+            MaterialDesignIcon::Close => "close",
+            MaterialDesignIcon::Cog => "cog",
+            MaterialDesignIcon::Delete => "delete",
+            MaterialDesignIcon::Download => "download",
+            MaterialDesignIcon::Email => "email",
+            MaterialDesignIcon::Eye => "eye",
+            MaterialDesignIcon::EyeOff => "eye-off",
+            MaterialDesignIcon::Heart => "heart",
+            MaterialDesignIcon::Home => "home",
+            MaterialDesignIcon::Information => "information",
+            MaterialDesignIcon::Loading => "loading",
+            MaterialDesignIcon::Lock => "lock",
+            MaterialDesignIcon::Logout => "logout",
+            MaterialDesignIcon::Magnify => "magnify",
+            MaterialDesignIcon::Menu => "menu",
+            MaterialDesignIcon::Minus => "minus",
+            MaterialDesignIcon::Pencil => "pencil",
+            MaterialDesignIcon::Plus => "plus",
+            MaterialDesignIcon::Star => "star",
+            MaterialDesignIcon::Upload => "upload",
+            MaterialDesignIcon::Other(name) => name,
+        }
+    }
+}
+
+/// The [Material Design Icons](https://materialdesignicons.com/)
+/// [IconSet].
+#[derive(Debug)]
+pub struct MaterialDesignIcons;
+
+impl IconSet for MaterialDesignIcons {
+    type Name = MaterialDesignIcon;
+
+    fn classes(name: &Self::Name, size: IconSize) -> Vec<String> {
+        vec![
+            "mdi".to_string(),
+            format!("mdi-{}", name.to_str()),
+            size.material_design_class(),
+        ]
+    }
+}
+
+/// An icon from an [IconSet], with the type of the set erased so
+/// that [SetIcon] can hold an icon from any set.
+trait ErasedIcon: Debug {
+    fn classes(&self, size: IconSize) -> Vec<String>;
+    fn as_any(&self) -> &dyn Any;
+    fn eq_icon(&self, other: &dyn ErasedIcon) -> bool;
+}
+
+struct TypedIcon<S: IconSet> {
+    name: S::Name,
+    set: PhantomData<S>,
+}
+
+impl<S: IconSet> Debug for TypedIcon<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.name)
+    }
+}
+
+impl<S: IconSet> ErasedIcon for TypedIcon<S> {
+    fn classes(&self, size: IconSize) -> Vec<String> {
+        S::classes(&self.name, size)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_icon(&self, other: &dyn ErasedIcon) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .map(|other| other.name == self.name)
+            .unwrap_or(false)
+    }
+}
+
+/// An icon from any [IconSet], see [IconSource::from_set]. Icons are
+/// equal if they belong to the same set and have the same name.
+#[derive(Clone, Debug)]
+pub struct SetIcon(Rc<dyn ErasedIcon>);
+
+impl SetIcon {
+    pub fn new<S: IconSet>(name: S::Name) -> Self {
+        Self(Rc::new(TypedIcon::<S> {
+            name,
+            set: PhantomData,
+        }))
+    }
+
+    /// The classes of the `<i>` element which displays this icon,
+    /// see [IconSet::classes].
+    pub fn classes(&self, size: IconSize) -> Vec<String> {
+        self.0.classes(size)
+    }
+}
+
+impl PartialEq for SetIcon {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_icon(other.0.as_ref())
+    }
+}
+
+/// What an [Icon] displays.
+#[derive(PartialEq, Clone, Debug)]
+pub enum IconSource {
+    /// An icon from an [IconSet], such as [FontAwesome] or
+    /// [MaterialDesignIcons]. See [IconSource::from_set].
+    Set(SetIcon),
+    /// The classes of the `<i>` element, such as `["fas", "fa-home"]`,
+    /// for icon fonts which have no [IconSet]. These are used as is,
+    /// regardless of the size of the icon.
+    Classes(Vec<String>),
+    /// Inline `<svg>` markup, for apps which can't load an icon font.
+    /// The markup should size the image relative to the container,
+    /// for example with `width="100%" height="100%"`.
+    ///
+    /// **The markup is inserted into the page with `innerHTML`, so it
+    /// must be trusted**, for example bundled with the app. Markup
+    /// which contains user input can run arbitrary scripts in the
+    /// page.
+    Svg(String),
+}
+
+impl IconSource {
+    /// An icon from the [IconSet] `S`.
+    pub fn from_set<S: IconSet>(name: S::Name) -> Self {
+        IconSource::Set(SetIcon::new::<S>(name))
+    }
+
+    /// The classes of the `<i>` element which displays the icon
+    /// within an `icon` container of the specified `size`, or `None`
+    /// for [IconSource::Svg].
+    pub fn classes(&self, size: IconSize) -> Option<Vec<String>> {
+        match self {
+            IconSource::Set(icon) => Some(icon.classes(size)),
+            IconSource::Classes(classes) => Some(classes.clone()),
+            IconSource::Svg(_) => None,
+        }
+    }
+}
+
+impl From<FontAwesomeIcon> for IconSource {
+    fn from(name: FontAwesomeIcon) -> Self {
+        IconSource::from_set::<FontAwesome>(name)
+    }
+}
+
+impl From<MaterialDesignIcon> for IconSource {
+    fn from(name: MaterialDesignIcon) -> Self {
+        IconSource::from_set::<MaterialDesignIcons>(name)
+    }
+}
+
+/// `IconContent` component, which renders the image of an icon (the
+/// `<i>` element, or the inline SVG) without the `icon` container.
+/// This is used by [Icon], and by components with their own icon
+/// container such as a `panel-icon`.
+///
+/// SVG markup is only parsed when the component is created, or when
+/// its `source` changes.
+#[derive(Debug)]
+pub struct IconContent {
+    /// The element containing the parsed markup of an
+    /// [IconSource::Svg].
+    svg: Option<Element>,
+    props: IconContentProps,
+}
+
+/// [Properties](yew::Component::Properties) for [IconContent].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct IconContentProps {
+    /// What the icon displays.
+    pub source: IconSource,
+    /// (Optional) The size of the icon container that the icon is
+    /// displayed within.
+    #[prop_or_default]
+    pub size: IconSize,
+}
+
+impl IconContent {
+    /// Parse the markup of an [IconSource::Svg] into a container
+    /// element.
+    fn parse_svg(source: &IconSource) -> Option<Element> {
+        let svg = match source {
+            IconSource::Svg(svg) => svg,
+            _ => return None,
+        };
+
+        let container = document()
+            .create_element("span")
+            .expect("unable to create icon svg container");
+        container.set_inner_html(svg);
+        container
+            .set_attribute("aria-hidden", "true")
+            .expect("unable to set aria-hidden on icon svg container");
+        Some(container)
+    }
+}
+
+impl Component for IconContent {
+    type Message = ();
+    type Properties = IconContentProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self {
+            svg: Self::parse_svg(&props.source),
+            props,
+        }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props.source != props.source {
+            self.svg = Self::parse_svg(&props.source);
+        }
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        if let Some(svg) = &self.svg {
+            return Html::VRef(svg.clone().into());
+        }
+
+        let class = self
+            .props
+            .source
+            .classes(self.props.size)
+            .unwrap_or_default();
+        html! { <i class=class aria-hidden="true"></i> }
+    }
+}
+
+/// `Icon` component.
 #[derive(Debug, Clone)]
 pub struct Icon {
     pub props: Props,
-    link: ComponentLink<Self>,
 }
 
+/// [Properties](yew::Component::Properties) for [Icon].
 #[derive(PartialEq, Clone, Properties, Debug)]
 pub struct Props {
+    /// What the icon displays.
+    pub source: IconSource,
     #[prop_or_default]
     pub color: Option<Color>,
+    /// (Optional) The size of the icon container, and of the icon
+    /// within it.
+    #[prop_or_default]
+    pub size: IconSize,
     #[prop_or_default]
     pub span_class: Vec<String>,
-    pub class: Vec<String>,
+}
+
+impl Props {
+    /// Create new `Props` for an icon displaying `source`, with the
+    /// default color and size.
+    pub fn new<S: Into<IconSource>>(source: S) -> Self {
+        Self {
+            source: source.into(),
+            color: None,
+            size: IconSize::default(),
+            span_class: Vec::new(),
+        }
+    }
+
+    /// Factory method to set the color of the icon.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Factory method to set the size of the icon.
+    pub fn size(mut self, size: IconSize) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl Icon {
+    fn content_props(&self) -> IconContentProps {
+        IconContentProps {
+            source: self.props.source.clone(),
+            size: self.props.size,
+        }
+    }
 }
 
 impl Component for Icon {
     type Message = ();
     type Properties = Props;
 
-    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Icon { props, link }
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Icon { props }
     }
 
     fn update(&mut self, _: Self::Message) -> ShouldRender {
@@ -35,16 +567,16 @@ impl Component for Icon {
     fn view(&self) -> Html {
         let mut span_class = vec!["icon".to_string()];
 
-        match &self.props.color {
-            Some(color) => span_class.push(color.text_class()),
-            None => {}
+        if let Some(color) = &self.props.color {
+            span_class.extend(color.text_class());
         }
 
+        span_class.extend(self.props.size.to_class());
         span_class.extend(self.props.span_class.clone());
 
         html! {
             <span class=span_class>
-                <i class=self.props.class.clone()></i>
+                <IconContent with self.content_props()/>
             </span>
         }
     }
@@ -53,3 +585,63 @@ impl Component for Icon {
         self.props.neq_assign(props)
     }
 }
+
+/// `IconText` component, which displays an [Icon] followed by a
+/// label.
+#[derive(Debug)]
+pub struct IconText {
+    props: IconTextProps,
+}
+
+/// [Properties](yew::Component::Properties) for [IconText].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct IconTextProps {
+    /// The icon displayed before the label.
+    pub icon_props: Props,
+    /// The label, typically text.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) Render the `icon-text` as a `<div>` rather than an
+    /// inline `<span>`, so that it takes up the whole width of its
+    /// container. By default this is `false`.
+    #[prop_or(false)]
+    pub is_block: bool,
+    /// (Optional) Extra classes to apply to the `icon-text`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for IconText {
+    type Message = ();
+    type Properties = IconTextProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["icon-text".to_string()];
+        classes.extend(self.props.classes.clone());
+
+        let content = html! {
+            <>
+                <Icon with self.props.icon_props.clone()/>
+                <span>{ self.props.children.clone() }</span>
+            </>
+        };
+
+        if self.props.is_block {
+            html! { <div class=classes>{ content }</div> }
+        } else {
+            html! { <span class=classes>{ content }</span> }
+        }
+    }
+}
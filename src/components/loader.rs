@@ -0,0 +1,114 @@
+//! A `yew` [Component](yew::Component) to cover content with a
+//! `bulma` [loader](https://bulma.io/documentation/elements/other/)
+//! while it is busy.
+
+use crate::classes::{AlignItems, Classes, Display, Flexbox, JustifyContent};
+
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// The default [Props::overlay_style] of a [Loader].
+pub const DEFAULT_OVERLAY_STYLE: &str = "background-color: rgba(255, 255, 255, 0.7); z-index: 10;";
+
+/// `Loader` component, which displays its children, and covers them
+/// with an overlay containing a spinning `loader` while `active` is
+/// `true`.
+///
+/// This is typically wrapped around a
+/// [Form](crate::components::form::Form) while its `onsubmit` is
+/// pending (in combination with the `Form`'s `submitting`
+/// property), or around a [Card](crate::components::card::Card)
+/// while its content, such as the options of a
+/// [SelectField](crate::components::form::select_field::SelectField),
+/// is loading (see its `onloading` property).
+#[derive(Debug)]
+pub struct Loader {
+    props: Props,
+}
+
+/// [Properties](yew::Component::Properties) for [Loader].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The content which is covered while loading.
+    #[prop_or_default]
+    pub children: Children,
+    /// Whether the content is busy, and the overlay is displayed.
+    pub active: bool,
+    /// (Optional) The `aria-label` of the loader. By default this is
+    /// `"Loading"`.
+    #[prop_or("Loading".to_string())]
+    pub label: String,
+    /// (Optional) The CSS `font-size` of the loader, which sets its
+    /// size. By default this is `"3rem"`.
+    #[prop_or("3rem".to_string())]
+    pub loader_size: String,
+    /// (Optional) Extra classes to apply to the overlay, for example
+    /// to set its background color and `z-index` from the app's
+    /// stylesheet.
+    #[prop_or_default]
+    pub overlay_classes: Vec<String>,
+    /// (Optional) The inline `style` of the overlay. By default this
+    /// gives the overlay a translucent white background and a
+    /// `z-index` of `10`. Set this to `None` to style the overlay
+    /// with `overlay_classes` instead.
+    #[prop_or(Some(DEFAULT_OVERLAY_STYLE.to_string()))]
+    pub overlay_style: Option<String>,
+    /// (Optional) Extra classes to apply to the element containing
+    /// the content and the overlay.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Loader {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let overlay = if self.props.active {
+            let mut overlay_classes: Vec<String> = Classes::new()
+                .class("is-overlay")
+                .display(Display::Flex)
+                .flexbox(Flexbox::JustifyContent(JustifyContent::Center))
+                .flexbox(Flexbox::AlignItems(AlignItems::Center))
+                .into();
+            overlay_classes.extend(self.props.overlay_classes.clone());
+
+            html! {
+                <div
+                    class=overlay_classes
+                    style=self.props.overlay_style.clone()>
+                    <div
+                        class="loader"
+                        role="status"
+                        aria-label=self.props.label.clone()
+                        style=format!("font-size: {};", self.props.loader_size)>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <div
+                class=self.props.classes.clone()
+                style="position: relative;"
+                aria-busy=self.props.active.to_string()>
+                { self.props.children.clone() }
+                { overlay }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [message](https://bulma.io/documentation/components/message/).
+
+use crate::classes::{ControlSize, MainColor};
+
+use yew::{html, Callback, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+
+/// `Message` component, which displays its children within the
+/// `message-body`.
+///
+/// If the message is `dismissable`, a delete button is displayed in
+/// the `message-header`, which hides the message and triggers the
+/// `ondismiss` callback. The message is displayed again if its
+/// `children` or `header` change.
+#[derive(Debug)]
+pub struct Message {
+    dismissed: bool,
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Dismiss,
+}
+
+/// [Properties](yew::Component::Properties) for [Message].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The content of the `message-body`.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The text of the `message-header`. The header is
+    /// not displayed if this is `None`, unless the message is
+    /// `dismissable`.
+    #[prop_or_default]
+    pub header: Option<String>,
+    /// (Optional) The color of the message.
+    #[prop_or_default]
+    pub color: Option<MainColor>,
+    /// (Optional) The size of the message.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) Whether to display a delete button which dismisses
+    /// the message. By default this is `false`.
+    #[prop_or(false)]
+    pub dismissable: bool,
+    /// (Optional) Callback for when the message is dismissed.
+    #[prop_or_default]
+    pub ondismiss: Callback<()>,
+    /// (Optional) Extra classes to apply to the `message`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Message {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            dismissed: false,
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Dismiss => {
+                self.dismissed = true;
+                self.props.ondismiss.emit(());
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            // A dismissed message is displayed again when its content
+            // changes.
+            if self.props.children != props.children || self.props.header != props.header {
+                self.dismissed = false;
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if self.dismissed {
+            return html! {};
+        }
+
+        let mut classes = vec!["message".to_string()];
+
+        if let Some(color) = &self.props.color {
+            classes.push(color.class());
+        }
+
+        classes.extend(self.props.size.to_class());
+        classes.extend(self.props.classes.clone());
+
+        let delete = if self.props.dismissable {
+            html! {
+                <button
+                    class="delete"
+                    aria-label="delete"
+                    onclick=self.link.callback(|_| Msg::Dismiss)>
+                </button>
+            }
+        } else {
+            html! {}
+        };
+
+        let header = if self.props.header.is_some() || self.props.dismissable {
+            html! {
+                <div class="message-header">
+                    <p>{ self.props.header.clone().unwrap_or_default() }</p>
+                    { delete }
+                </div>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <article class=classes>
+                { header }
+                <div class="message-body">
+                    { self.props.children.clone() }
+                </div>
+            </article>
+        }
+    }
+}
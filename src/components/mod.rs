@@ -3,6 +3,23 @@
 //! produce the correct HTML and attributes to be compatible with
 //! `bulma`.
 
+pub mod box_element;
+pub mod breadcrumb;
+pub mod button;
+pub mod card;
+pub mod columns;
+pub mod dropdown;
 pub mod form;
 pub mod icon;
+pub mod loader;
+pub mod message;
+pub mod modal;
+pub mod navbar;
+pub mod notification;
+pub mod pagination;
+pub mod panel;
+pub mod progress;
 pub mod select;
+pub mod table;
+pub mod tabs;
+pub mod title;
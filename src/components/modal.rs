@@ -0,0 +1,542 @@
+//! `yew` [Component](yew::Component)s to render a `bulma`
+//! [modal](https://bulma.io/documentation/components/modal/).
+//!
+//! A modal can be opened and closed using its `active` property, or
+//! using a [ModalLink] which can be cloned and shared with the
+//! components that need to control the modal.
+
+use crate::components::form::focus::focus_element;
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent};
+use yew::{
+    html, utils::document, Callback, Children, Component, ComponentLink, Html, NodeRef, Properties,
+    ShouldRender,
+};
+
+/// Selector for the elements within a modal which can receive focus,
+/// used to trap the focus within the modal.
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]), select:not([disabled]), textarea:not([disabled]), \
+    [tabindex]:not([tabindex=\"-1\"])";
+
+/// A handle used to open and close a [Modal] or [ModalCard]. Clones
+/// of the handle share the same state, so it can be given to any
+/// component which needs to control the modal.
+#[derive(Clone, Debug, Default)]
+pub struct ModalLink {
+    open: Rc<RefCell<bool>>,
+    listeners: Rc<RefCell<HashMap<Uuid, Callback<bool>>>>,
+}
+
+impl PartialEq for ModalLink {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.open, &other.open)
+    }
+}
+
+impl ModalLink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the modal is currently open.
+    pub fn is_open(&self) -> bool {
+        *self.open.borrow()
+    }
+
+    /// Open the modal.
+    pub fn open(&self) {
+        self.set_open(true);
+    }
+
+    /// Close the modal.
+    pub fn close(&self) {
+        self.set_open(false);
+    }
+
+    /// Open the modal if it is closed, or close it if it is open.
+    pub fn toggle(&self) {
+        self.set_open(!self.is_open());
+    }
+
+    fn set_open(&self, open: bool) {
+        self.store_open(open);
+        // Release the borrow before notifying, as a listener may
+        // (un)register listeners in response.
+        let listeners: Vec<Callback<bool>> = self.listeners.borrow().values().cloned().collect();
+        for listener in listeners {
+            listener.emit(open);
+        }
+    }
+
+    /// Record whether the modal is open without notifying the
+    /// listeners, used when the modal is opened or closed using its
+    /// `active` property.
+    fn store_open(&self, open: bool) {
+        *self.open.borrow_mut() = open;
+    }
+
+    /// Register a `callback` which is triggered whenever the modal is
+    /// opened or closed using this link. Returns an id which can be
+    /// used to unregister the listener with
+    /// [ModalLink::unregister_listener].
+    pub fn register_listener(&self, callback: Callback<bool>) -> Uuid {
+        let id = Uuid::new_v4();
+        self.listeners.borrow_mut().insert(id, callback);
+        id
+    }
+
+    /// Unregister a listener which was registered with
+    /// [ModalLink::register_listener].
+    pub fn unregister_listener(&self, id: &Uuid) {
+        self.listeners.borrow_mut().remove(id);
+    }
+}
+
+/// `Modal` component, which displays its children within the
+/// `modal-content` element.
+///
+/// While the modal is open, the browser's focus is trapped within
+/// the modal, and returned to the previously focused element when
+/// the modal closes or is removed.
+#[derive(Debug)]
+pub struct Modal {
+    open: bool,
+    /// Whether the focus should be moved into the modal once it has
+    /// been rendered.
+    focus_pending: bool,
+    /// The element which had focus before the modal was opened.
+    previous_focus: Option<HtmlElement>,
+    /// The id of the listener registered with [Props::modal_link].
+    listener_id: Option<Uuid>,
+    modal_ref: NodeRef,
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    /// Open or close the modal.
+    SetOpen(bool),
+    /// Close the modal because of an interaction within the modal.
+    Close,
+    KeyDown(KeyboardEvent),
+}
+
+/// [Properties](yew::Component::Properties) for [Modal].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The content of the modal.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) Whether the modal is open. When this changes the
+    /// modal is opened or closed accordingly. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub active: bool,
+    /// (Optional) A link used to open and close the modal.
+    #[prop_or_default]
+    pub modal_link: Option<ModalLink>,
+    /// (Optional) Callback for when the modal is closed by the user,
+    /// or using the `modal_link`.
+    #[prop_or_default]
+    pub onclose: Callback<()>,
+    /// (Optional) Whether clicking on the background closes the
+    /// modal. By default this is `true`.
+    #[prop_or(true)]
+    pub close_on_background: bool,
+    /// (Optional) Whether pressing `Escape` closes the modal. By
+    /// default this is `true`.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// (Optional) Whether to display the `modal-close` button. By
+    /// default this is `true`.
+    #[prop_or(true)]
+    pub show_close: bool,
+    /// (Optional) Whether the children are a `modal-card`, instead
+    /// of being wrapped in a `modal-content` element. This is used
+    /// by [ModalCard]. By default this is `false`.
+    #[prop_or(false)]
+    pub is_card: bool,
+    /// (Optional) The `aria-labelledby` attribute of the modal.
+    #[prop_or_default]
+    pub aria_labelledby: Option<String>,
+}
+
+impl Modal {
+    fn set_open(&mut self, open: bool) -> ShouldRender {
+        if self.open == open {
+            return false;
+        }
+
+        self.open = open;
+
+        if let Some(modal_link) = &self.props.modal_link {
+            modal_link.store_open(open);
+        }
+
+        if open {
+            self.previous_focus = document()
+                .active_element()
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+            self.focus_pending = true;
+        } else if let Some(element) = self.previous_focus.take() {
+            focus_element(&element);
+        }
+        true
+    }
+
+    /// The elements within the modal which can receive focus.
+    fn focusable_elements(&self) -> Vec<HtmlElement> {
+        let modal = match self.modal_ref.cast::<HtmlElement>() {
+            Some(modal) => modal,
+            None => return Vec::new(),
+        };
+
+        let nodes = match modal.query_selector_all(FOCUSABLE_SELECTOR) {
+            Ok(nodes) => nodes,
+            Err(_) => return Vec::new(),
+        };
+
+        (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+            .collect()
+    }
+
+    /// Keep the focus within the modal when the user presses `Tab`.
+    fn trap_focus(&self, event: &KeyboardEvent) {
+        let focusable = self.focusable_elements();
+
+        let (first, last) = match (focusable.first(), focusable.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                event.prevent_default();
+                return;
+            }
+        };
+
+        let active = document()
+            .active_element()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+        let modal = self.modal_ref.cast::<HtmlElement>();
+
+        if event.shift_key() {
+            if active.as_ref() == Some(first) || active == modal {
+                event.prevent_default();
+                focus_element(last);
+            }
+        } else if active.as_ref() == Some(last) {
+            event.prevent_default();
+            focus_element(first);
+        }
+    }
+
+    fn register_listener(&mut self) {
+        if let Some(modal_link) = &self.props.modal_link {
+            self.listener_id = Some(modal_link.register_listener(self.link.callback(Msg::SetOpen)));
+        }
+    }
+
+    fn unregister_listener(&mut self) {
+        if let (Some(modal_link), Some(id)) = (&self.props.modal_link, self.listener_id.take()) {
+            modal_link.unregister_listener(&id);
+        }
+    }
+}
+
+impl Component for Modal {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let open = props.active
+            || props
+                .modal_link
+                .as_ref()
+                .map(ModalLink::is_open)
+                .unwrap_or(false);
+
+        let mut modal = Self {
+            open: false,
+            focus_pending: false,
+            previous_focus: None,
+            listener_id: None,
+            modal_ref: NodeRef::default(),
+            props,
+            link,
+        };
+        modal.register_listener();
+        modal.set_open(open);
+        modal
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SetOpen(open) => {
+                let should_render = self.set_open(open);
+                if should_render && !open {
+                    self.props.onclose.emit(());
+                }
+                should_render
+            }
+            Msg::Close => match &self.props.modal_link {
+                // The link notifies this modal with Msg::SetOpen.
+                Some(modal_link) => {
+                    modal_link.close();
+                    false
+                }
+                None => self.update(Msg::SetOpen(false)),
+            },
+            Msg::KeyDown(event) => {
+                match event.key().as_str() {
+                    "Escape" if self.props.close_on_escape => {
+                        event.prevent_default();
+                        return self.update(Msg::Close);
+                    }
+                    "Tab" => self.trap_focus(&event),
+                    _ => {}
+                }
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        let active_changed = self.props.active != props.active;
+
+        if self.props.modal_link != props.modal_link {
+            self.unregister_listener();
+            self.props = props;
+            self.register_listener();
+        } else {
+            self.props = props;
+        }
+
+        if active_changed {
+            self.set_open(self.props.active);
+        }
+        true
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.focus_pending {
+            self.focus_pending = false;
+            match self.focusable_elements().first() {
+                Some(element) => focus_element(element),
+                None => {
+                    if let Some(modal) = self.modal_ref.cast::<HtmlElement>() {
+                        focus_element(&modal);
+                    }
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["modal"];
+        if self.open {
+            classes.push("is-active");
+        }
+
+        let onclick_background = if self.props.close_on_background {
+            self.link.callback(|_| Msg::Close)
+        } else {
+            Callback::noop()
+        };
+
+        let content = if self.props.is_card {
+            html! { <>{ self.props.children.clone() }</> }
+        } else {
+            html! {
+                <div class="modal-content">
+                    { self.props.children.clone() }
+                </div>
+            }
+        };
+
+        let close = if self.props.show_close && !self.props.is_card {
+            html! {
+                <button
+                    class="modal-close is-large"
+                    aria-label="close"
+                    onclick=self.link.callback(|_| Msg::Close)>
+                </button>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <div
+                class=classes
+                ref=self.modal_ref.clone()
+                role="dialog"
+                aria-modal="true"
+                aria-hidden=(!self.open).then_some("true")
+                aria-labelledby=self.props.aria_labelledby.clone()
+                tabindex="-1"
+                onkeydown=self.link.callback(Msg::KeyDown)>
+                <div class="modal-background" onclick=onclick_background></div>
+                { content }
+                { close }
+            </div>
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.unregister_listener();
+        if self.open {
+            if let Some(element) = self.previous_focus.take() {
+                focus_element(&element);
+            }
+        }
+    }
+}
+
+/// `ModalCard` component, a [Modal] which displays its children in
+/// the body of a `modal-card`, with a header containing a title and
+/// a close button, and an optional footer.
+#[derive(Debug)]
+pub struct ModalCard {
+    /// The link passed to the inner [Modal], either
+    /// [CardProps::modal_link] or a link owned by this component, so
+    /// that the header's close button can close the modal.
+    modal_link: ModalLink,
+    title_id: String,
+    props: CardProps,
+}
+
+/// [Properties](yew::Component::Properties) for [ModalCard].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct CardProps {
+    /// The content of the `modal-card-body`.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The title displayed in the `modal-card-head`.
+    #[prop_or_default]
+    pub title: Option<String>,
+    /// (Optional) Extra content to display in the `modal-card-head`,
+    /// after the title.
+    #[prop_or_default]
+    pub header: Option<Html>,
+    /// (Optional) The content of the `modal-card-foot`. The footer
+    /// is not displayed if this is `None`.
+    #[prop_or_default]
+    pub footer: Option<Html>,
+    /// See [Props::active].
+    #[prop_or(false)]
+    pub active: bool,
+    /// See [Props::modal_link].
+    #[prop_or_default]
+    pub modal_link: Option<ModalLink>,
+    /// See [Props::onclose].
+    #[prop_or_default]
+    pub onclose: Callback<()>,
+    /// See [Props::close_on_background].
+    #[prop_or(true)]
+    pub close_on_background: bool,
+    /// See [Props::close_on_escape].
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// (Optional) Whether to display the close button in the
+    /// `modal-card-head`. By default this is `true`.
+    #[prop_or(true)]
+    pub show_close: bool,
+}
+
+impl Component for ModalCard {
+    type Message = ();
+    type Properties = CardProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self {
+            modal_link: props.modal_link.clone().unwrap_or_default(),
+            title_id: format!("modal-title-{}", Uuid::new_v4()),
+            props,
+        }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        if self.props.modal_link != props.modal_link {
+            self.modal_link = props.modal_link.clone().unwrap_or_default();
+        }
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let title = match &self.props.title {
+            Some(title) => html! {
+                <p class="modal-card-title" id=self.title_id.clone()>{ title }</p>
+            },
+            None => html! {},
+        };
+
+        let close = if self.props.show_close {
+            let modal_link = self.modal_link.clone();
+            html! {
+                <button
+                    class="delete"
+                    aria-label="close"
+                    onclick=Callback::from(move |_| modal_link.close())>
+                </button>
+            }
+        } else {
+            html! {}
+        };
+
+        let footer = match &self.props.footer {
+            Some(footer) => html! {
+                <footer class="modal-card-foot">{ footer.clone() }</footer>
+            },
+            None => html! {},
+        };
+
+        let aria_labelledby = self.props.title.as_ref().map(|_| self.title_id.clone());
+
+        let card = html! {
+            <div class="modal-card">
+                <header class="modal-card-head">
+                    { title }
+                    { self.props.header.clone().unwrap_or_default() }
+                    { close }
+                </header>
+                <section class="modal-card-body">
+                    { self.props.children.clone() }
+                </section>
+                { footer }
+            </div>
+        };
+
+        let props = Props {
+            children: Children::new(vec![card]),
+            active: self.props.active,
+            modal_link: Some(self.modal_link.clone()),
+            onclose: self.props.onclose.clone(),
+            close_on_background: self.props.close_on_background,
+            close_on_escape: self.props.close_on_escape,
+            show_close: false,
+            is_card: true,
+            aria_labelledby,
+        };
+
+        html! {
+            <Modal with props/>
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! `yew` [Component](yew::Component)s to render a `bulma`
+//! [navbar](https://bulma.io/documentation/components/navbar/).
+//!
+//! The [Navbar] has `brand`, `start` and `end` slots, which are
+//! typically filled with [NavbarItem]s and [NavbarDropdown]s. On
+//! touch devices the `start` and `end` slots are hidden in a menu
+//! which is toggled using the navbar's burger.
+
+use crate::classes::MainColor;
+
+use uuid::Uuid;
+use web_sys::MouseEvent;
+use yew::{html, Callback, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// `Navbar` component.
+#[derive(Debug)]
+pub struct Navbar {
+    /// Whether the burger and menu are active (the menu is open on
+    /// touch devices).
+    active: bool,
+    menu_id: String,
+    props: NavbarProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum NavbarMsg {
+    /// Open the menu if it is closed, or close it if it is open.
+    ToggleMenu,
+}
+
+/// [Properties](yew::Component::Properties) for [Navbar].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct NavbarProps {
+    /// (Optional) The content of the `navbar-brand`, which is always
+    /// visible, typically a logo.
+    #[prop_or_default]
+    pub brand: Html,
+    /// (Optional) The content of the `navbar-start`, on the left of
+    /// the menu.
+    #[prop_or_default]
+    pub start: Html,
+    /// (Optional) The content of the `navbar-end`, on the right of
+    /// the menu.
+    #[prop_or_default]
+    pub end: Html,
+    /// (Optional) The color of the navbar.
+    #[prop_or_default]
+    pub color: Option<MainColor>,
+    /// (Optional) Apply the `is-fixed-top` style. The page's `<html>`
+    /// or `<body>` element requires the `has-navbar-fixed-top` class
+    /// to make room for the navbar. By default this is `false`.
+    #[prop_or(false)]
+    pub is_fixed_top: bool,
+    /// (Optional) Apply the `is-transparent` style, which removes the
+    /// hover and active backgrounds of the navbar items. By default
+    /// this is `false`.
+    #[prop_or(false)]
+    pub is_transparent: bool,
+    /// (Optional) Apply the `is-spaced` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_spaced: bool,
+    /// (Optional) The `aria-label` of the navbar. By default this is
+    /// `"main navigation"`.
+    #[prop_or("main navigation".to_string())]
+    pub aria_label: String,
+}
+
+impl Component for Navbar {
+    type Message = NavbarMsg;
+    type Properties = NavbarProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            active: false,
+            menu_id: format!("navbar-menu-{}", Uuid::new_v4()),
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            NavbarMsg::ToggleMenu => {
+                self.active = !self.active;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["navbar".to_string()];
+
+        if let Some(color) = &self.props.color {
+            classes.push(color.class());
+        }
+
+        let flags = [
+            (self.props.is_fixed_top, "is-fixed-top"),
+            (self.props.is_transparent, "is-transparent"),
+            (self.props.is_spaced, "is-spaced"),
+        ];
+
+        classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+
+        let mut burger_classes = vec!["navbar-burger"];
+        let mut menu_classes = vec!["navbar-menu"];
+
+        if self.active {
+            burger_classes.push("is-active");
+            menu_classes.push("is-active");
+        }
+
+        let onclick_burger = self.link.callback(|event: MouseEvent| {
+            event.prevent_default();
+            NavbarMsg::ToggleMenu
+        });
+
+        html! {
+            <nav class=classes role="navigation" aria-label=self.props.aria_label.clone()>
+                <div class="navbar-brand">
+                    { self.props.brand.clone() }
+                    <a
+                        role="button"
+                        class=burger_classes
+                        aria-label="menu"
+                        aria-expanded=self.active.to_string()
+                        aria-controls=self.menu_id.clone()
+                        onclick=onclick_burger>
+                        <span aria-hidden="true"></span>
+                        <span aria-hidden="true"></span>
+                        <span aria-hidden="true"></span>
+                    </a>
+                </div>
+                <div id=self.menu_id.clone() class=menu_classes>
+                    <div class="navbar-start">
+                        { self.props.start.clone() }
+                    </div>
+                    <div class="navbar-end">
+                        { self.props.end.clone() }
+                    </div>
+                </div>
+            </nav>
+        }
+    }
+}
+
+/// `NavbarItem` component, a `navbar-item` which is rendered as a
+/// link.
+#[derive(Debug)]
+pub struct NavbarItem {
+    props: NavbarItemProps,
+}
+
+/// [Properties](yew::Component::Properties) for [NavbarItem].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct NavbarItemProps {
+    /// The content of the item.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The `href` of the link.
+    #[prop_or_default]
+    pub href: Option<String>,
+    /// (Optional) Callback for when the item is clicked.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+    /// (Optional) Apply the `is-active` style, for example for the
+    /// item of the current page. By default this is `false`.
+    #[prop_or(false)]
+    pub is_active: bool,
+    /// (Optional) Apply the `is-tab` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_tab: bool,
+    /// (Optional) Extra classes to apply to the item.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for NavbarItem {
+    type Message = ();
+    type Properties = NavbarItemProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["navbar-item".to_string()];
+
+        if self.props.is_active {
+            classes.push("is-active".to_string());
+        }
+
+        if self.props.is_tab {
+            classes.push("is-tab".to_string());
+        }
+
+        classes.extend(self.props.classes.clone());
+
+        html! {
+            <a
+                class=classes
+                href=self.props.href.clone()
+                aria-current=self.props.is_active.then_some("page")
+                onclick=self.props.onclick.clone()>
+                { self.props.children.clone() }
+            </a>
+        }
+    }
+}
+
+/// `NavbarDropdown` component, a `navbar-item` with a
+/// `navbar-dropdown` menu, which opens when hovered over (if
+/// `is_hoverable` is `true`) or when its link is clicked.
+#[derive(Debug)]
+pub struct NavbarDropdown {
+    active: bool,
+    props: NavbarDropdownProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum NavbarDropdownMsg {
+    /// Open the dropdown if it is closed, or close it if it is open.
+    Toggle,
+}
+
+/// [Properties](yew::Component::Properties) for [NavbarDropdown].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct NavbarDropdownProps {
+    /// The text of the `navbar-link` which opens the dropdown.
+    pub label: String,
+    /// The content of the dropdown, typically [NavbarItem]s and
+    /// [NavbarDivider]s.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) Apply the `is-hoverable` style, which opens the
+    /// dropdown when it is hovered over. By default this is `true`.
+    #[prop_or(true)]
+    pub is_hoverable: bool,
+    /// (Optional) Apply the `is-right` style to the dropdown menu. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub is_right: bool,
+    /// (Optional) Apply the `has-dropdown-up` style, which opens the
+    /// dropdown above the navbar. By default this is `false`.
+    #[prop_or(false)]
+    pub is_up: bool,
+    /// (Optional) Apply the `is-boxed` style to the dropdown menu. By
+    /// default this is `false`.
+    #[prop_or(false)]
+    pub is_boxed: bool,
+}
+
+impl Component for NavbarDropdown {
+    type Message = NavbarDropdownMsg;
+    type Properties = NavbarDropdownProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            active: false,
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            NavbarDropdownMsg::Toggle => {
+                self.active = !self.active;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["navbar-item".to_string(), "has-dropdown".to_string()];
+
+        let flags = [
+            (self.active, "is-active"),
+            (self.props.is_hoverable, "is-hoverable"),
+            (self.props.is_up, "has-dropdown-up"),
+        ];
+
+        classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+
+        let mut dropdown_classes = vec!["navbar-dropdown".to_string()];
+
+        if self.props.is_right {
+            dropdown_classes.push("is-right".to_string());
+        }
+
+        if self.props.is_boxed {
+            dropdown_classes.push("is-boxed".to_string());
+        }
+
+        let onclick = self.link.callback(|event: MouseEvent| {
+            event.prevent_default();
+            NavbarDropdownMsg::Toggle
+        });
+
+        html! {
+            <div class=classes>
+                <a
+                    class="navbar-link"
+                    role="button"
+                    aria-haspopup="true"
+                    aria-expanded=self.active.to_string()
+                    onclick=onclick>
+                    { &self.props.label }
+                </a>
+                <div class=dropdown_classes>
+                    { self.props.children.clone() }
+                </div>
+            </div>
+        }
+    }
+}
+
+/// `NavbarDivider` component, a `navbar-divider` between the items of
+/// a [NavbarDropdown].
+#[derive(Debug)]
+pub struct NavbarDivider;
+
+impl Component for NavbarDivider {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! { <hr class="navbar-divider"/> }
+    }
+}
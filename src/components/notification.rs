@@ -0,0 +1,321 @@
+//! An app-wide notification service which renders `bulma`
+//! [notifications](https://bulma.io/documentation/elements/notification/).
+//!
+//! A [NotificationsProvider] renders the notifications which are
+//! pushed to it using a [NotificationsLink]. The link can be cloned
+//! and given to any component which needs to display notifications,
+//! for example to display the result of submitting a
+//! [Form](crate::components::form::Form) in the `onsubmit` callback.
+
+use crate::classes::MainColor;
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use uuid::Uuid;
+use yew::{
+    html,
+    services::{timeout::TimeoutTask, TimeoutService},
+    Children, Component, ComponentLink, Html, Properties, ShouldRender,
+};
+
+/// A message to be displayed by a [NotificationsProvider].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Notification {
+    /// The text of the notification.
+    pub message: String,
+    /// (Optional) The color of the notification.
+    pub color: Option<MainColor>,
+    /// Whether to apply the `is-light` style, if the color has one.
+    pub is_light: bool,
+    /// (Optional) How long to display the notification before it is
+    /// dismissed automatically. If this is `None`, the
+    /// [NotificationsProviderProps::default_timeout] is used.
+    pub timeout: Option<Duration>,
+    /// Whether the notification can be dismissed by the user with a
+    /// delete button.
+    pub dismissable: bool,
+}
+
+impl Notification {
+    /// Create a new dismissable `Notification` without a color.
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            message: message.into(),
+            color: None,
+            is_light: false,
+            timeout: None,
+            dismissable: true,
+        }
+    }
+
+    /// Factory method to set the color of the notification.
+    pub fn color(mut self, color: MainColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Factory method to set whether the notification uses the
+    /// `is-light` style.
+    pub fn light(mut self, is_light: bool) -> Self {
+        self.is_light = is_light;
+        self
+    }
+
+    /// Factory method to set how long the notification is displayed
+    /// before it is dismissed automatically.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Factory method to set whether the notification can be
+    /// dismissed by the user.
+    pub fn dismissable(mut self, dismissable: bool) -> Self {
+        self.dismissable = dismissable;
+        self
+    }
+}
+
+type ProviderLink = ComponentLink<NotificationsProvider>;
+
+/// A handle used to push notifications to a [NotificationsProvider].
+/// Clones of the handle share the same provider.
+///
+/// Notifications which are pushed before the provider has been
+/// created are displayed once it has been created.
+#[derive(Clone, Debug, Default)]
+pub struct NotificationsLink {
+    provider_link: Rc<RefCell<Option<ProviderLink>>>,
+    /// Notifications pushed before the provider was registered.
+    pending: Rc<RefCell<Vec<(Uuid, Notification)>>>,
+}
+
+impl PartialEq for NotificationsLink {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.provider_link, &other.provider_link)
+    }
+}
+
+impl NotificationsLink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Display a notification. Returns an id which can be used to
+    /// dismiss the notification with [NotificationsLink::dismiss].
+    pub fn push(&self, notification: Notification) -> Uuid {
+        let id = Uuid::new_v4();
+        // The borrow is released before sending, as the provider may
+        // update synchronously and use this link.
+        let provider_link = self.provider_link.borrow().clone();
+        match provider_link {
+            Some(link) => link.send_message(NotificationsMsg::Push(id, notification)),
+            None => self.pending.borrow_mut().push((id, notification)),
+        }
+        id
+    }
+
+    /// Dismiss the notification with the specified `id`.
+    pub fn dismiss(&self, id: Uuid) {
+        let provider_link = self.provider_link.borrow().clone();
+        match provider_link {
+            Some(link) => link.send_message(NotificationsMsg::Dismiss(id)),
+            None => self
+                .pending
+                .borrow_mut()
+                .retain(|(pending_id, _)| *pending_id != id),
+        }
+    }
+
+    /// Dismiss all the notifications.
+    pub fn clear(&self) {
+        let provider_link = self.provider_link.borrow().clone();
+        match provider_link {
+            Some(link) => link.send_message(NotificationsMsg::Clear),
+            None => self.pending.borrow_mut().clear(),
+        }
+    }
+
+    pub fn provider_is_registered(&self) -> bool {
+        self.provider_link.borrow().is_some()
+    }
+
+    /// Register the provider which displays the notifications, and
+    /// send it any notifications which were pushed beforehand.
+    pub fn register_provider(&self, link: ProviderLink) {
+        for (id, notification) in self.pending.borrow_mut().drain(..) {
+            link.send_message(NotificationsMsg::Push(id, notification));
+        }
+        *self.provider_link.borrow_mut() = Some(link);
+    }
+
+    /// Unregister the provider, so that notifications pushed
+    /// afterwards are queued until another provider is registered.
+    /// This is called by the [NotificationsProvider] when it is
+    /// destroyed.
+    pub fn unregister_provider(&self) {
+        *self.provider_link.borrow_mut() = None;
+    }
+}
+
+/// A notification which is currently being displayed.
+#[derive(Debug)]
+struct ActiveNotification {
+    id: Uuid,
+    notification: Notification,
+    /// Timer to automatically dismiss the notification, which is
+    /// cancelled when dropped.
+    _timeout_task: Option<TimeoutTask>,
+}
+
+/// A component which renders its children, followed by the
+/// notifications which have been pushed using its
+/// [NotificationsLink].
+#[derive(Debug)]
+pub struct NotificationsProvider {
+    notifications: Vec<ActiveNotification>,
+    props: NotificationsProviderProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum NotificationsMsg {
+    Push(Uuid, Notification),
+    Dismiss(Uuid),
+    Clear,
+}
+
+/// [Properties](yew::Component::Properties) for
+/// [NotificationsProvider].
+#[derive(Clone, Properties, PartialEq, Debug)]
+pub struct NotificationsProviderProps {
+    /// The link used to push notifications to this provider.
+    pub notifications_link: NotificationsLink,
+    /// (Optional) The content of the app, rendered before the
+    /// notifications.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) How long to display notifications which don't
+    /// specify their own [Notification::timeout]. If this is `None`
+    /// these notifications are displayed until they are dismissed.
+    /// By default this is `None`.
+    #[prop_or_default]
+    pub default_timeout: Option<Duration>,
+    /// (Optional) Extra classes to apply to the element containing
+    /// the notifications, for example to position them.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl NotificationsProvider {
+    fn view_notification(&self, active: &ActiveNotification) -> Html {
+        let notification = &active.notification;
+        let mut classes = vec!["notification".to_string()];
+
+        if let Some(color) = &notification.color {
+            classes.push(color.class());
+            if notification.is_light {
+                classes.extend(color.light_class());
+            }
+        }
+
+        let delete = if notification.dismissable {
+            let id = active.id;
+            let onclick = self.link.callback(move |_| NotificationsMsg::Dismiss(id));
+            html! {
+                <button class="delete" aria-label="delete" onclick=onclick></button>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <div class=classes key=active.id.to_string()>
+                { delete }
+                { &notification.message }
+            </div>
+        }
+    }
+}
+
+impl Component for NotificationsProvider {
+    type Message = NotificationsMsg;
+    type Properties = NotificationsProviderProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        props.notifications_link.register_provider(link.clone());
+
+        Self {
+            notifications: Vec::new(),
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            NotificationsMsg::Push(id, notification) => {
+                let timeout_task =
+                    notification
+                        .timeout
+                        .or(self.props.default_timeout)
+                        .map(|timeout| {
+                            TimeoutService::spawn(
+                                timeout,
+                                self.link.callback(move |_| NotificationsMsg::Dismiss(id)),
+                            )
+                        });
+
+                self.notifications.push(ActiveNotification {
+                    id,
+                    notification,
+                    _timeout_task: timeout_task,
+                });
+                true
+            }
+            NotificationsMsg::Dismiss(id) => {
+                let len = self.notifications.len();
+                self.notifications.retain(|active| active.id != id);
+                self.notifications.len() != len
+            }
+            NotificationsMsg::Clear => {
+                let should_render = !self.notifications.is_empty();
+                self.notifications.clear();
+                should_render
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.notifications_link != props.notifications_link {
+                self.props.notifications_link.unregister_provider();
+                if !props.notifications_link.provider_is_registered() {
+                    props
+                        .notifications_link
+                        .register_provider(self.link.clone());
+                }
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn destroy(&mut self) {
+        self.props.notifications_link.unregister_provider();
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["notifications".to_string()];
+        classes.extend(self.props.classes.clone());
+
+        html! {
+            <>
+                { self.props.children.clone() }
+                <div class=classes aria-live="polite">
+                    { for self.notifications.iter().map(|active| self.view_notification(active)) }
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [pagination](https://bulma.io/documentation/components/pagination/).
+
+use crate::classes::ControlSize;
+
+use yew::{html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// An entry in the list of page links of a [Pagination].
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum PageItem {
+    /// A link to the page with this (1-based) number.
+    Page(usize),
+    /// An ellipsis representing pages which are not displayed.
+    Ellipsis,
+}
+
+/// Calculate which page links and ellipses to display for the
+/// specified `current_page` out of `total_pages` (both 1-based).
+///
+/// The first and last pages are always displayed, along with
+/// `siblings` pages on either side of the `current_page`. Any other
+/// pages are replaced by an ellipsis, unless the ellipsis would only
+/// replace a single page, in which case that page is displayed
+/// instead.
+///
+/// ```
+/// use yew_bulma::components::pagination::{page_window, PageItem};
+///
+/// assert_eq!(
+///     page_window(46, 86, 1),
+///     vec![
+///         PageItem::Page(1),
+///         PageItem::Ellipsis,
+///         PageItem::Page(45),
+///         PageItem::Page(46),
+///         PageItem::Page(47),
+///         PageItem::Ellipsis,
+///         PageItem::Page(86),
+///     ]
+/// );
+///
+/// assert_eq!(
+///     page_window(2, 5, 1),
+///     vec![
+///         PageItem::Page(1),
+///         PageItem::Page(2),
+///         PageItem::Page(3),
+///         PageItem::Page(4),
+///         PageItem::Page(5),
+///     ]
+/// );
+///
+/// assert_eq!(page_window(1, 0, 1), vec![]);
+/// ```
+pub fn page_window(current_page: usize, total_pages: usize, siblings: usize) -> Vec<PageItem> {
+    if total_pages == 0 {
+        return Vec::new();
+    }
+
+    let current_page = current_page.clamp(1, total_pages);
+    let window_start = current_page.saturating_sub(siblings).max(1);
+    let window_end = current_page.saturating_add(siblings).min(total_pages);
+
+    let mut pages = vec![1];
+    pages.extend(window_start..=window_end);
+    pages.push(total_pages);
+    pages.dedup();
+
+    let mut items = Vec::with_capacity(pages.len() + 2);
+    let mut previous: Option<usize> = None;
+
+    for page in pages {
+        match previous {
+            Some(previous) if page == previous + 2 => items.push(PageItem::Page(previous + 1)),
+            Some(previous) if page > previous + 2 => items.push(PageItem::Ellipsis),
+            _ => {}
+        }
+        items.push(PageItem::Page(page));
+        previous = Some(page);
+    }
+
+    items
+}
+
+/// The horizontal alignment of the page links in a [Pagination].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum PaginationAlignment {
+    /// The page links are on the left, followed by the previous and
+    /// next buttons.
+    #[default]
+    Left,
+    /// The page links are between the previous and next buttons.
+    Centered,
+    /// The previous and next buttons are on the left, followed by the
+    /// page links.
+    Right,
+}
+
+impl PaginationAlignment {
+    pub fn to_class(&self) -> Option<String> {
+        match self {
+            PaginationAlignment::Left => None,
+            PaginationAlignment::Centered => Some("is-centered".to_string()),
+            PaginationAlignment::Right => Some("is-right".to_string()),
+        }
+    }
+}
+
+/// `Pagination` component.
+#[derive(Debug)]
+pub struct Pagination {
+    props: Props,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    /// Change to the page with the specified (1-based) number.
+    Change(usize),
+}
+
+/// [Properties](yew::Component::Properties) for [Pagination].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The (1-based) number of the current page.
+    pub current_page: usize,
+    /// The total number of pages.
+    pub total_pages: usize,
+    /// (Optional) Callback for when a different page is chosen, with
+    /// the (1-based) number of the chosen page.
+    #[prop_or_default]
+    pub onchange: Callback<usize>,
+    /// (Optional) The number of pages to display on either side of
+    /// the current page, see [page_window]. By default this is `1`.
+    #[prop_or(1)]
+    pub siblings: usize,
+    /// (Optional) The size of the pagination.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) The alignment of the page links.
+    #[prop_or_default]
+    pub alignment: PaginationAlignment,
+    /// (Optional) Apply the `is-rounded` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_rounded: bool,
+    /// (Optional) The text of the previous page button. By default
+    /// this is `"Previous"`.
+    #[prop_or("Previous".to_string())]
+    pub previous_label: String,
+    /// (Optional) The text of the next page button. By default this
+    /// is `"Next"`.
+    #[prop_or("Next".to_string())]
+    pub next_label: String,
+}
+
+impl Pagination {
+    /// The current page, limited to the range of pages in the same
+    /// way as [page_window].
+    fn current_page(&self) -> usize {
+        self.props
+            .current_page
+            .clamp(1, self.props.total_pages.max(1))
+    }
+
+    /// Render the previous or next page button, which changes to
+    /// `page`, or is disabled if `page` is `None`.
+    fn view_step(&self, class: &str, label: &str, page: Option<usize>) -> Html {
+        let mut classes = vec![class.to_string()];
+
+        match page {
+            Some(page) => html! {
+                <a class=classes onclick=self.link.callback(move |_| Msg::Change(page))>
+                    { label }
+                </a>
+            },
+            None => {
+                classes.push("is-disabled".to_string());
+                html! {
+                    <a class=classes disabled=true aria-disabled="true">{ label }</a>
+                }
+            }
+        }
+    }
+
+    fn view_item(&self, item: &PageItem) -> Html {
+        match *item {
+            PageItem::Page(page) => {
+                let current = page == self.current_page();
+                let mut classes = vec!["pagination-link".to_string()];
+                if current {
+                    classes.push("is-current".to_string());
+                }
+                html! {
+                    <li>
+                        <a
+                            class=classes
+                            aria-label=format!("Page {}", page)
+                            aria-current=current.then_some("page")
+                            onclick=self.link.callback(move |_| Msg::Change(page))>
+                            { page }
+                        </a>
+                    </li>
+                }
+            }
+            PageItem::Ellipsis => html! {
+                <li><span class="pagination-ellipsis">{ "…" }</span></li>
+            },
+        }
+    }
+}
+
+impl Component for Pagination {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { props, link }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Change(page) => {
+                if page != self.current_page() && (1..=self.props.total_pages).contains(&page) {
+                    self.props.onchange.emit(page);
+                }
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["pagination".to_string()];
+        classes.extend(self.props.size.to_class());
+        classes.extend(self.props.alignment.to_class());
+        if self.props.is_rounded {
+            classes.push("is-rounded".to_string());
+        }
+
+        let current_page = self.current_page();
+        let total_pages = self.props.total_pages;
+        let previous = (current_page > 1).then(|| current_page - 1);
+        let next = (current_page < total_pages).then(|| current_page + 1);
+
+        let items = page_window(current_page, total_pages, self.props.siblings);
+
+        html! {
+            <nav class=classes role="navigation" aria-label="pagination">
+                { self.view_step("pagination-previous", &self.props.previous_label, previous) }
+                { self.view_step("pagination-next", &self.props.next_label, next) }
+                <ul class="pagination-list">
+                    { for items.iter().map(|item| self.view_item(item)) }
+                </ul>
+            </nav>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_window, PageItem};
+
+    use PageItem::{Ellipsis, Page};
+
+    #[test]
+    fn no_pages() {
+        assert_eq!(page_window(1, 0, 1), vec![]);
+        assert_eq!(page_window(0, 0, 0), vec![]);
+    }
+
+    #[test]
+    fn single_page() {
+        assert_eq!(page_window(1, 1, 1), vec![Page(1)]);
+        assert_eq!(page_window(1, 1, 0), vec![Page(1)]);
+    }
+
+    #[test]
+    fn current_page_zero_is_first_page() {
+        assert_eq!(
+            page_window(0, 5, 1),
+            vec![Page(1), Page(2), Ellipsis, Page(5)]
+        );
+        assert_eq!(page_window(0, 5, 1), page_window(1, 5, 1));
+    }
+
+    #[test]
+    fn current_page_past_total_is_last_page() {
+        assert_eq!(
+            page_window(10, 5, 1),
+            vec![Page(1), Ellipsis, Page(4), Page(5)]
+        );
+        assert_eq!(page_window(10, 5, 1), page_window(5, 5, 1));
+    }
+
+    #[test]
+    fn no_siblings() {
+        assert_eq!(
+            page_window(5, 9, 0),
+            vec![Page(1), Ellipsis, Page(5), Ellipsis, Page(9)]
+        );
+        // An ellipsis which would only replace a single page is
+        // replaced by that page.
+        assert_eq!(
+            page_window(3, 5, 0),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+    }
+
+    #[test]
+    fn window_touching_first_page() {
+        assert_eq!(
+            page_window(1, 10, 1),
+            vec![Page(1), Page(2), Ellipsis, Page(10)]
+        );
+        assert_eq!(
+            page_window(2, 10, 1),
+            vec![Page(1), Page(2), Page(3), Ellipsis, Page(10)]
+        );
+        assert_eq!(
+            page_window(4, 10, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn window_touching_last_page() {
+        assert_eq!(
+            page_window(10, 10, 1),
+            vec![Page(1), Ellipsis, Page(9), Page(10)]
+        );
+        assert_eq!(
+            page_window(9, 10, 1),
+            vec![Page(1), Ellipsis, Page(8), Page(9), Page(10)]
+        );
+        assert_eq!(
+            page_window(7, 10, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn siblings_cover_all_pages() {
+        assert_eq!(
+            page_window(3, 5, 10),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+    }
+}
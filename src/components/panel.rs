@@ -0,0 +1,292 @@
+//! `yew` [Component](yew::Component)s to render a `bulma`
+//! [panel](https://bulma.io/documentation/components/panel/).
+
+use crate::{classes::MainColor, components::icon};
+
+use web_sys::MouseEvent;
+use yew::{
+    html, Callback, Children, Component, ComponentLink, Html, InputData, Properties, ShouldRender,
+};
+use yewtil::NeqAssign;
+
+/// `Panel` component, which displays its children (typically
+/// [PanelBlock]s) beneath an optional heading, `panel-tabs` and
+/// search input.
+#[derive(Debug)]
+pub struct Panel {
+    /// Index into [PanelProps::tabs] of the active tab.
+    active_tab: Option<usize>,
+    search: String,
+    props: PanelProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum PanelMsg {
+    /// Activate the tab with the specified index.
+    ActivateTab(usize),
+    /// The user has typed into the search input.
+    Search(String),
+}
+
+/// [Properties](yew::Component::Properties) for [Panel].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct PanelProps {
+    /// The content of the panel, typically [PanelBlock]s.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) The text of the `panel-heading`.
+    #[prop_or_default]
+    pub heading: Option<String>,
+    /// (Optional) The color of the panel.
+    #[prop_or_default]
+    pub color: Option<MainColor>,
+    /// (Optional) The labels of the tabs displayed in the
+    /// `panel-tabs`. The tabs are not displayed if this is empty.
+    #[prop_or_default]
+    pub tabs: Vec<String>,
+    /// (Optional) The index of the initially active tab. By default
+    /// the first tab is active.
+    #[prop_or_default]
+    pub active_tab: Option<usize>,
+    /// (Optional) Callback for when a tab is chosen, with the index
+    /// of the tab in `tabs`.
+    #[prop_or_default]
+    pub ontabchange: Callback<usize>,
+    /// (Optional) Whether to display a search input. By default this
+    /// is `false`.
+    #[prop_or(false)]
+    pub show_search: bool,
+    /// (Optional) The placeholder of the search input. By default
+    /// this is `"Search"`.
+    #[prop_or("Search".to_string())]
+    pub search_placeholder: String,
+    /// (Optional) Callback for when the user types into the search
+    /// input, with the text of the search input.
+    #[prop_or_default]
+    pub onsearch: Callback<String>,
+    /// (Optional) An icon to display in the search input.
+    #[prop_or_default]
+    pub search_icon_props: Option<icon::Props>,
+}
+
+impl Panel {
+    fn active_tab(props: &PanelProps) -> Option<usize> {
+        props
+            .active_tab
+            .filter(|index| *index < props.tabs.len())
+            .or_else(|| (!props.tabs.is_empty()).then_some(0))
+    }
+
+    fn view_tabs(&self) -> Html {
+        if self.props.tabs.is_empty() {
+            return html! {};
+        }
+
+        let view_tab = |(index, label): (usize, &String)| {
+            let onclick = self.link.callback(move |event: MouseEvent| {
+                event.prevent_default();
+                PanelMsg::ActivateTab(index)
+            });
+            let active = self.active_tab == Some(index);
+
+            html! {
+                <a
+                    href="#"
+                    class=active.then_some("is-active")
+                    aria-current=active.then_some("true")
+                    onclick=onclick>
+                    { label }
+                </a>
+            }
+        };
+
+        html! {
+            <p class="panel-tabs">
+                { for self.props.tabs.iter().enumerate().map(view_tab) }
+            </p>
+        }
+    }
+
+    fn view_search(&self) -> Html {
+        if !self.props.show_search {
+            return html! {};
+        }
+
+        let (control_class, icon) = match &self.props.search_icon_props {
+            Some(icon_props) => {
+                let mut icon_props = icon_props.clone();
+                icon_props.span_class.push("is-left".to_string());
+                (
+                    "control has-icons-left",
+                    html! { <icon::Icon with icon_props/> },
+                )
+            }
+            None => ("control", html! {}),
+        };
+
+        html! {
+            <div class="panel-block">
+                <p class=control_class>
+                    <input
+                        class="input"
+                        type="search"
+                        placeholder=self.props.search_placeholder.clone()
+                        aria-label=self.props.search_placeholder.clone()
+                        value=self.search.clone()
+                        oninput=self.link.callback(|data: InputData| PanelMsg::Search(data.value))/>
+                    { icon }
+                </p>
+            </div>
+        }
+    }
+}
+
+impl Component for Panel {
+    type Message = PanelMsg;
+    type Properties = PanelProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            active_tab: Self::active_tab(&props),
+            search: String::new(),
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            PanelMsg::ActivateTab(index) => {
+                if self.active_tab == Some(index) {
+                    return false;
+                }
+                self.active_tab = Some(index);
+                self.props.ontabchange.emit(index);
+                true
+            }
+            PanelMsg::Search(search) => {
+                self.search = search.clone();
+                self.props.onsearch.emit(search);
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        if self.props.active_tab != props.active_tab || self.props.tabs != props.tabs {
+            self.active_tab = Self::active_tab(&props);
+        }
+
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["panel".to_string()];
+
+        if let Some(color) = &self.props.color {
+            classes.push(color.class());
+        }
+
+        let heading = match &self.props.heading {
+            Some(heading) => html! { <p class="panel-heading">{ heading }</p> },
+            None => html! {},
+        };
+
+        html! {
+            <nav class=classes>
+                { heading }
+                { self.view_search() }
+                { self.view_tabs() }
+                { self.props.children.clone() }
+            </nav>
+        }
+    }
+}
+
+/// `PanelBlock` component, a `panel-block` within a [Panel], which
+/// is rendered as a link.
+#[derive(Debug)]
+pub struct PanelBlock {
+    props: PanelBlockProps,
+}
+
+/// [Properties](yew::Component::Properties) for [PanelBlock].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct PanelBlockProps {
+    /// The content of the block.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) An icon to display in the `panel-icon`, before the
+    /// content of the block.
+    #[prop_or_default]
+    pub icon_props: Option<icon::Props>,
+    /// (Optional) The `href` of the link.
+    #[prop_or_default]
+    pub href: Option<String>,
+    /// (Optional) Callback for when the block is clicked.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+    /// (Optional) Apply the `is-active` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_active: bool,
+}
+
+impl Component for PanelBlock {
+    type Message = ();
+    type Properties = PanelBlockProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["panel-block".to_string()];
+
+        if self.props.is_active {
+            classes.push("is-active".to_string());
+        }
+
+        let icon = match &self.props.icon_props {
+            Some(icon_props) => {
+                let mut span_class = vec!["panel-icon".to_string()];
+                if let Some(color) = &icon_props.color {
+                    span_class.extend(color.text_class());
+                }
+                let content_props = icon::IconContentProps {
+                    source: icon_props.source.clone(),
+                    size: icon_props.size,
+                };
+                html! {
+                    <span class=span_class>
+                        <icon::IconContent with content_props/>
+                    </span>
+                }
+            }
+            None => html! {},
+        };
+
+        html! {
+            <a
+                class=classes
+                href=self.props.href.clone()
+                onclick=self.props.onclick.clone()>
+                { icon }
+                { self.props.children.clone() }
+            </a>
+        }
+    }
+}
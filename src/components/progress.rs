@@ -0,0 +1,93 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [progress bar](https://bulma.io/documentation/elements/progress/).
+
+use crate::classes::{ControlSize, MainColor};
+
+use yew::{html, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// `Progress` component.
+#[derive(Debug)]
+pub struct Progress {
+    props: Props,
+}
+
+/// [Properties](yew::Component::Properties) for [Progress].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// (Optional) The amount of progress, out of `max`. If this is
+    /// `None` the progress bar is indeterminate, and displays an
+    /// animation instead. By default this is `None`.
+    #[prop_or_default]
+    pub value: Option<f64>,
+    /// (Optional) The value at which the progress is complete. If
+    /// this is not greater than zero, the progress bar is
+    /// indeterminate. By default this is `100.0`.
+    #[prop_or(100.0)]
+    pub max: f64,
+    /// (Optional) The color of the progress bar.
+    #[prop_or_default]
+    pub color: Option<MainColor>,
+    /// (Optional) The size of the progress bar.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) The `aria-label` of the progress bar, describing
+    /// what is in progress.
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+    /// (Optional) Extra classes to apply to the `progress`.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Progress {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["progress".to_string()];
+
+        if let Some(color) = &self.props.color {
+            classes.push(color.class());
+        }
+
+        classes.extend(self.props.size.to_class());
+        classes.extend(self.props.classes.clone());
+
+        let max = self.props.max;
+        // `f64::clamp` panics if `max` is negative or NaN.
+        let value = if max > 0.0 {
+            self.props.value.map(|value| value.clamp(0.0, max))
+        } else {
+            None
+        };
+        let text = match value {
+            Some(value) => format!("{:.0}%", value / max * 100.0),
+            None => String::new(),
+        };
+
+        // `yew` only sets the `value` of `<input>` and `<textarea>`
+        // elements, unless the tag name is dynamic.
+        html! {
+            <@{"progress"}
+                class=classes
+                value=value.map(|value| value.to_string())
+                max=max.to_string()
+                aria-label=self.props.aria_label.clone()>
+                { text }
+            </@>
+        }
+    }
+}
@@ -1,12 +1,15 @@
 //! A `yew` [Component](yew::Component) to render a `bulma`
-//! [Dropdown](https://bulma.io/documentation/components/dropdown/).
+//! [select](https://bulma.io/documentation/form/select/). See
+//! [Dropdown](crate::components::dropdown::Dropdown) for a styled
+//! `bulma` dropdown.
 //!
 //! Modified version of the `Select` component in the Yew source code
 //! https://github.com/yewstack/yew/blob/8edf136da6ba1955c847c5860ec55623a27c08e9/src/components/select.rs
 //! License for original code:
 //! https://github.com/yewstack/yew/blob/master/LICENSE-APACHE
 
-use crate::{classes::Size, components::icon};
+use crate::{classes::ControlSize, components::icon};
+use std::fmt::Display;
 use web_sys::HtmlSelectElement;
 use yew::{
     callback::Callback,
@@ -15,18 +18,76 @@ use yew::{
     Properties,
 };
 
+/// A group label, and the options (with their index) that belong to
+/// the group.
+type OptionGroup<'a, T> = (Option<&'a str>, Vec<(usize, &'a SelectOption<T>)>);
+
+/// An option which can be chosen in a [Select].
+#[derive(PartialEq, Clone, Debug)]
+pub struct SelectOption<T> {
+    /// The value which is emitted when this option is chosen.
+    pub value: T,
+    /// The text displayed to the user for this option.
+    pub label: String,
+    /// Whether this option is disabled, preventing it from being
+    /// chosen.
+    pub disabled: bool,
+    /// (Optional) The label of the group that this option belongs to.
+    /// Consecutive options with the same group are rendered together
+    /// within an `<optgroup>`.
+    pub group: Option<String>,
+}
+
+impl<T> SelectOption<T> {
+    /// Create a new enabled `SelectOption` which doesn't belong to a
+    /// group.
+    pub fn new<L: Into<String>>(value: T, label: L) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            disabled: false,
+            group: None,
+        }
+    }
+
+    /// Factory method to set whether this option is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Factory method to set the group that this option belongs to.
+    pub fn group<G: Into<String>>(mut self, group: G) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+}
+
+/// Create an option using `value`'s `Display` implementation for the
+/// label.
+impl<T> From<T> for SelectOption<T>
+where
+    T: Display,
+{
+    fn from(value: T) -> Self {
+        let label = value.to_string();
+        Self::new(value, label)
+    }
+}
+
 /// `Select` component.
 #[derive(Debug)]
-pub struct Select<T: ToString + PartialEq + Clone + 'static> {
+pub struct Select<T: PartialEq + Clone + 'static> {
     props: Props<T>,
-    select_ref: NodeRef,
     link: ComponentLink<Self>,
 }
 
 /// Internal message of the component.
 #[derive(Debug)]
 pub enum Msg {
-    /// This message indicates the option with id selected.
+    /// This message indicates the option with the specified index
+    /// in [Props::options] was selected, or the empty option was
+    /// selected if the index is `None`.
     Selected(Option<usize>),
 }
 
@@ -39,41 +100,70 @@ pub struct Props<T: Clone> {
     #[prop_or_default]
     pub disabled: bool,
     /// Options are available to choose.
-    pub options: Vec<T>,
+    pub options: Vec<SelectOption<T>>,
     #[prop_or_default]
     pub div_classes: Vec<String>,
     #[prop_or_default]
     pub icon_props: Option<icon::Props>,
     #[prop_or_default]
-    pub size: Size,
-    /// Callback to handle changes.
+    pub size: ControlSize,
+    /// Callback to handle changes. This emits `None` when the empty
+    /// option is chosen, which is only possible when `allow_clear`
+    /// is `true`.
+    #[prop_or_default]
+    pub onchange: Callback<Option<T>>,
+    /// (Optional) The text of the empty option which is displayed
+    /// when there is no selected value. If this is `None`, the empty
+    /// option is hidden from the list of options (unless
+    /// `allow_clear` is `true`). By default this is `"↪"`.
+    #[prop_or(Some("↪".to_string()))]
+    pub placeholder: Option<String>,
+    /// (Optional) Whether the user can choose the empty option to
+    /// clear the selected value, in which case `onchange` emits
+    /// `None`. By default this is `false`.
+    #[prop_or(false)]
+    pub allow_clear: bool,
+    /// (Optional) A reference to the `<select>` element.
+    #[prop_or_default]
+    pub select_ref: NodeRef,
+    /// (Optional) The `id` of the `<select>` element.
+    #[prop_or_default]
+    pub id: Option<String>,
+    /// (Optional) Sets `aria-invalid="true"` on the `<select>`
+    /// element. By default this is `false`.
+    #[prop_or(false)]
+    pub aria_invalid: bool,
+    /// (Optional) Sets `aria-required="true"` on the `<select>`
+    /// element. By default this is `false`.
+    #[prop_or(false)]
+    pub aria_required: bool,
+    /// (Optional) The `aria-describedby` attribute of the `<select>`
+    /// element.
     #[prop_or_default]
-    pub onchange: Callback<T>,
+    pub aria_describedby: Option<String>,
 }
 
 impl<T> Component for Select<T>
 where
-    T: ToString + PartialEq + Clone + 'static,
+    T: PartialEq + Clone + 'static,
 {
     type Message = Msg;
     type Properties = Props<T>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self {
-            props,
-            select_ref: NodeRef::default(),
-            link,
-        }
+        Self { props, link }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::Selected(value) => {
-                if let Some(idx) = value {
-                    let item = self.props.options.get(idx - 1).cloned();
-                    if let Some(value) = item {
-                        self.props.onchange.emit(value);
-                    }
+            Msg::Selected(Some(idx)) => {
+                if let Some(option) = self.props.options.get(idx) {
+                    self.props.onchange.emit(Some(option.value.clone()));
+                }
+            }
+            Msg::Selected(None) => {
+                if self.props.allow_clear {
+                    self.props.onchange.emit(None);
                 }
             }
         }
@@ -82,11 +172,17 @@ where
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props.selected != props.selected {
-            if let Some(select) = self.select_ref.cast::<HtmlSelectElement>() {
+            if let Some(select) = self.props.select_ref.cast::<HtmlSelectElement>() {
                 let val = props
                     .selected
                     .as_ref()
-                    .map(|v| v.to_string())
+                    .and_then(|selected| {
+                        props
+                            .options
+                            .iter()
+                            .position(|option| &option.value == selected)
+                    })
+                    .map(|idx| idx.to_string())
                     .unwrap_or_default();
 
                 select.set_value(&val)
@@ -98,13 +194,38 @@ where
 
     fn view(&self) -> Html {
         let selected = self.props.selected.as_ref();
-        let view_option = |value: &T| {
-            let flag = selected == Some(value);
+        // The index of the option is used as its value, so that the
+        // selected option can be identified regardless of `T`.
+        let view_option = |(idx, option): &(usize, &SelectOption<T>)| {
+            let flag = selected == Some(&option.value);
             html! {
-                <option value=value.to_string() selected=flag>{ value.to_string() }</option>
+                <option value=idx.to_string() selected=flag disabled=option.disabled>
+                    { &option.label }
+                </option>
             }
         };
 
+        // Group consecutive options which belong to the same group.
+        let mut groups: Vec<OptionGroup<T>> = Vec::new();
+        for (idx, option) in self.props.options.iter().enumerate() {
+            let group = option.group.as_deref();
+            match groups.last_mut() {
+                Some((last_group, options)) if *last_group == group => options.push((idx, option)),
+                _ => groups.push((group, vec![(idx, option)])),
+            }
+        }
+
+        let view_group = |(group, options): &OptionGroup<T>| match group {
+            Some(group) => html! {
+                <optgroup label=group.to_string()>
+                    { for options.iter().map(view_option) }
+                </optgroup>
+            },
+            None => html! {
+                { for options.iter().map(view_option) }
+            },
+        };
+
         let mut div_classes = vec!["select".to_string()];
 
         let size_class_vec = match self.props.size.to_class() {
@@ -118,17 +239,22 @@ where
 
         let inner = html! {
             <div class=div_classes>
-                <select ref=self.select_ref.clone() disabled=self.props.disabled onchange=self.onchange()>
-                    <option value="" disabled=true selected=selected.is_none()>
-                        { "↪" }
-                    </option>
-                    { for self.props.options.iter().map(view_option) }
+                <select
+                    ref=self.props.select_ref.clone()
+                    id=self.props.id.clone()
+                    disabled=self.props.disabled
+                    onchange=self.onchange()
+                    aria-invalid=self.props.aria_invalid.then_some("true")
+                    aria-required=self.props.aria_required.then_some("true")
+                    aria-describedby=self.props.aria_describedby.clone()>
+                    { self.view_empty_option() }
+                    { for groups.iter().map(view_group) }
                 </select>
             </div>
         };
 
-        if self.props.icon_props.is_some() {
-            let mut icon_props = self.props.icon_props.as_ref().unwrap().clone();
+        if let Some(icon_props) = &self.props.icon_props {
+            let mut icon_props = icon_props.clone();
             icon_props.span_class.push("is-left".to_string());
 
             html! {
@@ -147,13 +273,34 @@ where
 
 impl<T> Select<T>
 where
-    T: ToString + PartialEq + Clone + 'static,
+    T: PartialEq + Clone + 'static,
 {
+    /// The option which is selected when there is no selected value.
+    fn view_empty_option(&self) -> Html {
+        let selected = self.props.selected.is_none();
+        let allow_clear = self.props.allow_clear;
+        match &self.props.placeholder {
+            Some(placeholder) => html! {
+                <option value="" disabled=!allow_clear selected=selected>
+                    { placeholder }
+                </option>
+            },
+            None if allow_clear => html! {
+                <option value="" selected=selected></option>
+            },
+            None => html! {
+                <option value="" disabled=true hidden=true selected=selected></option>
+            },
+        }
+    }
+
     fn onchange(&self) -> Callback<ChangeData> {
         self.link.callback(|event| match event {
             ChangeData::Select(elem) => {
-                let value = elem.selected_index();
-                let value = Some(value as usize);
+                // Options are rendered with their index as their
+                // value, options without an index (such as the
+                // placeholder) result in `None`.
+                let value = elem.value().parse::<usize>().ok();
                 Msg::Selected(value)
             }
             _ => {
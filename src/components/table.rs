@@ -0,0 +1,477 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [table](https://bulma.io/documentation/elements/table/) of rows,
+//! with sortable columns and selectable rows.
+//!
+//! The cells of a [Column] are rendered with an arbitrary function,
+//! so they can host form fields such as
+//! [InputField](crate::components::form::input_field::InputField)
+//! bound to a [FormFieldLink](crate::components::form::FormFieldLink)
+//! to make the table editable. The rows are keyed by their index in
+//! [Props::rows], so the fields keep their state when the table is
+//! sorted.
+
+use crate::components::form::checkbox_field::CheckboxState;
+
+use std::{cmp::Ordering, fmt::Debug, rc::Rc};
+use web_sys::MouseEvent;
+use yew::{html, Callback, Component, ComponentLink, Html, Properties, ShouldRender};
+
+type CellFn<Row> = Rc<dyn Fn(&Row, usize) -> Html>;
+type CompareFn<Row> = Rc<dyn Fn(&Row, &Row) -> Ordering>;
+
+/// The definition of a column in a [Table].
+///
+/// The `cell` and `compare` functions can't be compared, so columns
+/// are compared by their `key` (which is the header by default) and
+/// whether they are sortable. This means columns can be created in
+/// the parent's `view()` without re-rendering the table every time.
+/// If a function captures state which changes how the column is
+/// displayed, include that state in the column's key with
+/// [Column::key].
+pub struct Column<Row> {
+    /// The label displayed in the column's header.
+    pub header: String,
+    /// Identifies the column when comparing the [Props] of a [Table].
+    key: String,
+    /// Renders the cell for a row, given the row and its index in
+    /// [Props::rows].
+    cell: CellFn<Row>,
+    /// Compares two rows to sort the table by this column.
+    compare: Option<CompareFn<Row>>,
+}
+
+impl<Row> Column<Row> {
+    /// Create a new `Column` which is not sortable. The `cell`
+    /// function renders the content of the cell for a row, given the
+    /// row and its index in [Props::rows].
+    pub fn new<H, F>(header: H, cell: F) -> Self
+    where
+        H: Into<String>,
+        F: Fn(&Row, usize) -> Html + 'static,
+    {
+        let header = header.into();
+        Self {
+            key: header.clone(),
+            header,
+            cell: Rc::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Factory method to set the key which identifies this column
+    /// when the [Props] of a [Table] are compared. By default this is
+    /// the header.
+    pub fn key<K: Into<String>>(mut self, key: K) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Factory method to make the table sortable by this column,
+    /// using `compare` to order the rows.
+    pub fn sortable<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&Row, &Row) -> Ordering + 'static,
+    {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Whether the table can be sorted by this column.
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Render the cell for the `row` with the specified `index`.
+    pub fn cell(&self, row: &Row, index: usize) -> Html {
+        (self.cell)(row, index)
+    }
+}
+
+impl<Row> Clone for Column<Row> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            key: self.key.clone(),
+            cell: Rc::clone(&self.cell),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<Row> PartialEq for Column<Row> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.header == other.header
+            && self.is_sortable() == other.is_sortable()
+    }
+}
+
+impl<Row> Debug for Column<Row> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Column(header: {:?}, key: {:?})", self.header, self.key)
+    }
+}
+
+/// The direction in which a [Table] is sorted.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggle(&self) -> SortDirection {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+
+    /// The value of the `aria-sort` attribute for this direction.
+    fn aria_sort(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+
+    /// The indicator displayed in the header of the sorted column.
+    fn indicator(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        }
+    }
+}
+
+/// `Table` component.
+#[derive(Debug)]
+pub struct Table<Row: Clone + PartialEq + 'static> {
+    /// The index of the column that the table is sorted by, and the
+    /// direction.
+    sort: Option<(usize, SortDirection)>,
+    /// The selection state of each row in [Props::rows].
+    selection: Vec<CheckboxState>,
+    props: Props<Row>,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    /// Sort by the column with the specified index, or reverse the
+    /// direction if the table is already sorted by this column.
+    Sort(usize),
+    /// Toggle the selection of the row with the specified index.
+    ToggleRow(usize),
+    /// Toggle the selection of all the rows.
+    ToggleAll,
+}
+
+/// [Properties](yew::Component::Properties) for [Table].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props<Row: Clone> {
+    /// The columns of the table.
+    pub columns: Vec<Column<Row>>,
+    /// The rows of the table.
+    pub rows: Vec<Row>,
+    /// (Optional) Whether to display a column of checkboxes to select
+    /// rows. By default this is `false`.
+    #[prop_or(false)]
+    pub selectable: bool,
+    /// (Optional) Callback for when the selection changes, with the
+    /// selection state of each row in `rows`. When `rows` changes,
+    /// rows which are equal to a previously selected row remain
+    /// selected, and this is triggered if the selection changed.
+    #[prop_or_default]
+    pub onselect: Callback<Vec<CheckboxState>>,
+    /// (Optional) Apply the `is-bordered` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_bordered: bool,
+    /// (Optional) Apply the `is-striped` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_striped: bool,
+    /// (Optional) Apply the `is-hoverable` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_hoverable: bool,
+    /// (Optional) Apply the `is-fullwidth` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_fullwidth: bool,
+    /// (Optional) Apply the `is-narrow` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_narrow: bool,
+}
+
+impl<Row> Table<Row>
+where
+    Row: Clone + PartialEq + 'static,
+{
+    fn unselected(props: &Props<Row>) -> Vec<CheckboxState> {
+        vec![CheckboxState::Unchecked; props.rows.len()]
+    }
+
+    /// The selection state of each row in the `rows` of the new
+    /// `props`. Rows which are equal to a selected row in the current
+    /// [Props::rows] remain selected.
+    fn remap_selection(&self, props: &Props<Row>) -> Vec<CheckboxState> {
+        let mut selected: Vec<&Row> = self
+            .props
+            .rows
+            .iter()
+            .zip(&self.selection)
+            .filter(|(_, state)| state.checked())
+            .map(|(row, _)| row)
+            .collect();
+
+        props
+            .rows
+            .iter()
+            .map(
+                |row| match selected.iter().position(|selected| *selected == row) {
+                    Some(position) => {
+                        selected.swap_remove(position);
+                        CheckboxState::Checked
+                    }
+                    None => CheckboxState::Unchecked,
+                },
+            )
+            .collect()
+    }
+
+    /// The sorted column and direction for the new `props`. The table
+    /// remains sorted by the same column if it is still present and
+    /// sortable in the new [Props::columns], even if it has moved.
+    fn remap_sort(&self, props: &Props<Row>) -> Option<(usize, SortDirection)> {
+        let (column, direction) = self.sort?;
+        let column = self.props.columns.get(column)?;
+        props
+            .columns
+            .iter()
+            .position(|new_column| new_column == column && new_column.is_sortable())
+            .map(|index| (index, direction))
+    }
+
+    fn all_selected(&self) -> bool {
+        !self.selection.is_empty() && self.selection.iter().all(CheckboxState::checked)
+    }
+
+    /// The indices of the rows in [Props::rows], in the order that
+    /// they are displayed.
+    fn row_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.props.rows.len()).collect();
+
+        let sort = self.sort.and_then(|(column, direction)| {
+            self.props
+                .columns
+                .get(column)
+                .and_then(|column| column.compare.clone())
+                .map(|compare| (compare, direction))
+        });
+
+        if let Some((compare, direction)) = sort {
+            let rows = &self.props.rows;
+            order.sort_by(|a, b| {
+                let ordering = compare(&rows[*a], &rows[*b]);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        order
+    }
+
+    fn view_header(&self, index: usize, column: &Column<Row>) -> Html {
+        if !column.is_sortable() {
+            return html! { <th>{ &column.header }</th> };
+        }
+
+        let direction = self
+            .sort
+            .filter(|(sort_column, _)| *sort_column == index)
+            .map(|(_, direction)| direction);
+
+        let onclick = self.link.callback(move |event: MouseEvent| {
+            event.prevent_default();
+            Msg::Sort(index)
+        });
+
+        html! {
+            <th aria-sort=direction.map(|direction| direction.aria_sort()).unwrap_or("none")>
+                <a href="#" onclick=onclick>
+                    { &column.header }
+                    {
+                        match direction {
+                            Some(direction) => html! {
+                                <span class="icon is-small" aria-hidden="true">
+                                    { direction.indicator() }
+                                </span>
+                            },
+                            None => html! {},
+                        }
+                    }
+                </a>
+            </th>
+        }
+    }
+
+    fn view_row(&self, index: usize) -> Html {
+        let row = &self.props.rows[index];
+        let selected = self
+            .selection
+            .get(index)
+            .map(CheckboxState::checked)
+            .unwrap_or(false);
+
+        let select_cell = if self.props.selectable {
+            let onchange = self.link.callback(move |_| Msg::ToggleRow(index));
+            html! {
+                <td>
+                    <label class="checkbox">
+                        <input
+                            type="checkbox"
+                            checked=selected
+                            aria-label="Select row"
+                            onchange=onchange/>
+                    </label>
+                </td>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <tr key=index.to_string() class=selected.then_some("is-selected")>
+                { select_cell }
+                {
+                    for self.props.columns
+                        .iter()
+                        .map(|column| html! { <td>{ column.cell(row, index) }</td> })
+                }
+            </tr>
+        }
+    }
+}
+
+impl<Row> Component for Table<Row>
+where
+    Row: Clone + PartialEq + 'static,
+{
+    type Message = Msg;
+    type Properties = Props<Row>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            sort: None,
+            selection: Self::unselected(&props),
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Sort(column) => {
+                self.sort = match self.sort {
+                    Some((sort_column, direction)) if sort_column == column => {
+                        Some((column, direction.toggle()))
+                    }
+                    _ => Some((column, SortDirection::Ascending)),
+                };
+                true
+            }
+            Msg::ToggleRow(index) => {
+                if let Some(state) = self.selection.get_mut(index) {
+                    *state = state.toggle();
+                    self.props.onselect.emit(self.selection.clone());
+                }
+                true
+            }
+            Msg::ToggleAll => {
+                let state = CheckboxState::from(!self.all_selected());
+                self.selection = vec![state; self.props.rows.len()];
+                self.props.onselect.emit(self.selection.clone());
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        if self.props.rows != props.rows {
+            let selection = self.remap_selection(&props);
+            if selection != self.selection {
+                self.selection = selection;
+                props.onselect.emit(self.selection.clone());
+            }
+        }
+
+        if self.props.columns != props.columns {
+            self.sort = self.remap_sort(&props);
+        }
+
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["table".to_string()];
+
+        let flags = [
+            (self.props.is_bordered, "is-bordered"),
+            (self.props.is_striped, "is-striped"),
+            (self.props.is_hoverable, "is-hoverable"),
+            (self.props.is_fullwidth, "is-fullwidth"),
+            (self.props.is_narrow, "is-narrow"),
+        ];
+
+        classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+
+        let select_all = if self.props.selectable {
+            html! {
+                <th>
+                    <label class="checkbox">
+                        <input
+                            type="checkbox"
+                            checked=self.all_selected()
+                            aria-label="Select all rows"
+                            onchange=self.link.callback(|_| Msg::ToggleAll)/>
+                    </label>
+                </th>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <table class=classes>
+                <thead>
+                    <tr>
+                        { select_all }
+                        {
+                            for self.props.columns
+                                .iter()
+                                .enumerate()
+                                .map(|(index, column)| self.view_header(index, column))
+                        }
+                    </tr>
+                </thead>
+                <tbody>
+                    { for self.row_order().into_iter().map(|index| self.view_row(index)) }
+                </tbody>
+            </table>
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! `yew` [Component](yew::Component)s to render `bulma`
+//! [tabs](https://bulma.io/documentation/components/tabs/).
+//!
+//! [Tabs] renders the tabs themselves, and reports the active tab
+//! with its `onchange` callback. [TabPanels] is an optional companion
+//! which renders only the [TabPanel] for the active tab.
+
+use crate::{
+    classes::ControlSize,
+    components::{form::focus::focus_node, icon},
+};
+
+use uuid::Uuid;
+use web_sys::KeyboardEvent;
+use yew::{
+    html, Callback, Children, ChildrenWithProps, Component, ComponentLink, Html, NodeRef,
+    Properties, ShouldRender,
+};
+
+/// A tab which can be chosen in [Tabs].
+#[derive(PartialEq, Clone, Debug)]
+pub struct Tab<T> {
+    /// The value which is emitted when this tab is chosen.
+    pub value: T,
+    /// The text displayed on the tab.
+    pub label: String,
+    /// (Optional) An icon to display before the label.
+    pub icon_props: Option<icon::Props>,
+}
+
+impl<T> Tab<T> {
+    /// Create a new `Tab` without an icon.
+    pub fn new<L: Into<String>>(value: T, label: L) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            icon_props: None,
+        }
+    }
+
+    /// Factory method to set the icon displayed on the tab.
+    pub fn icon(mut self, icon_props: icon::Props) -> Self {
+        self.icon_props = Some(icon_props);
+        self
+    }
+}
+
+/// The `id` of the tab at `index`, for the tabs with the specified
+/// `id`.
+fn tab_id(id: &str, index: usize) -> String {
+    format!("{}-tab-{}", id, index)
+}
+
+/// The `id` of the panel at `index`, for the tabs with the specified
+/// `id`.
+fn panel_id(id: &str, index: usize) -> String {
+    format!("{}-panel-{}", id, index)
+}
+
+/// `Tabs` component.
+///
+/// The tabs can be navigated using the arrow keys, `Home` and `End`
+/// once one of them has focus, which activates the newly focused tab.
+#[derive(Debug)]
+pub struct Tabs<T: PartialEq + Clone + 'static> {
+    /// Index into [TabsProps::tabs] of the active tab.
+    active: Option<usize>,
+    /// Whether the active tab should receive focus once rendered.
+    focus_pending: bool,
+    id: String,
+    tab_refs: Vec<NodeRef>,
+    props: TabsProps<T>,
+    link: ComponentLink<Self>,
+}
+
+pub enum TabsMsg {
+    /// Activate the tab with the specified index.
+    Activate(usize),
+    KeyDown(KeyboardEvent),
+}
+
+/// [Properties](yew::Component::Properties) for [Tabs].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct TabsProps<T: Clone> {
+    /// The tabs available to choose.
+    pub tabs: Vec<Tab<T>>,
+    /// (Optional) The active tab. By default the first tab is
+    /// active.
+    #[prop_or_default]
+    pub active: Option<T>,
+    /// (Optional) Callback for when a different tab is chosen.
+    #[prop_or_default]
+    pub onchange: Callback<T>,
+    /// (Optional) The `id` used to create the `id`s of each tab, and
+    /// to link the tabs to the panels in [TabPanels] with the same
+    /// `tabs_id`. If this is `None` a unique `id` is generated, and
+    /// the tabs are not linked to any panels.
+    #[prop_or_default]
+    pub id: Option<String>,
+    /// (Optional) The size of the tabs.
+    #[prop_or_default]
+    pub size: ControlSize,
+    /// (Optional) Apply the `is-boxed` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_boxed: bool,
+    /// (Optional) Apply the `is-toggle` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_toggle: bool,
+    /// (Optional) Apply the `is-centered` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_centered: bool,
+    /// (Optional) Apply the `is-fullwidth` style. By default this is
+    /// `false`.
+    #[prop_or(false)]
+    pub is_fullwidth: bool,
+    /// (Optional) The `aria-label` of the `tablist`.
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+}
+
+impl<T> Tabs<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    /// The index of the tab in `props` with the value `active`, or the
+    /// first tab if there is no such tab.
+    fn active_index(props: &TabsProps<T>) -> Option<usize> {
+        props
+            .active
+            .as_ref()
+            .and_then(|active| props.tabs.iter().position(|tab| &tab.value == active))
+            .or_else(|| (!props.tabs.is_empty()).then_some(0))
+    }
+
+    fn tab_refs(props: &TabsProps<T>) -> Vec<NodeRef> {
+        props.tabs.iter().map(|_| NodeRef::default()).collect()
+    }
+
+    fn view_tab(&self, index: usize, tab: &Tab<T>) -> Html {
+        let active = self.active == Some(index);
+        let onclick = self.link.callback(move |_| TabsMsg::Activate(index));
+        let onkeydown = self.link.callback(TabsMsg::KeyDown);
+        let aria_controls = self.props.id.as_ref().map(|id| panel_id(id, index));
+
+        let icon = match &tab.icon_props {
+            Some(icon_props) => html! { <icon::Icon with icon_props.clone()/> },
+            None => html! {},
+        };
+
+        html! {
+            <li class=active.then_some("is-active") role="presentation">
+                <a
+                    ref=self.tab_refs[index].clone()
+                    id=tab_id(&self.id, index)
+                    role="tab"
+                    aria-selected=active.to_string()
+                    aria-controls=aria_controls
+                    tabindex=if active { "0" } else { "-1" }
+                    onclick=onclick
+                    onkeydown=onkeydown>
+                    { icon }
+                    <span>{ &tab.label }</span>
+                </a>
+            </li>
+        }
+    }
+}
+
+impl<T> Component for Tabs<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    type Message = TabsMsg;
+    type Properties = TabsProps<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            active: Self::active_index(&props),
+            focus_pending: false,
+            id: props
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("tabs-{}", Uuid::new_v4())),
+            tab_refs: Self::tab_refs(&props),
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            TabsMsg::Activate(index) => {
+                if self.active == Some(index) {
+                    return false;
+                }
+                match self.props.tabs.get(index) {
+                    Some(tab) => {
+                        self.active = Some(index);
+                        self.props.onchange.emit(tab.value.clone());
+                        true
+                    }
+                    None => false,
+                }
+            }
+            TabsMsg::KeyDown(event) => {
+                let len = self.props.tabs.len();
+                let current = match self.active {
+                    Some(current) if len > 0 => current,
+                    _ => return false,
+                };
+
+                let next = match event.key().as_str() {
+                    "ArrowRight" => (current + 1) % len,
+                    "ArrowLeft" => (current + len - 1) % len,
+                    "Home" => 0,
+                    "End" => len - 1,
+                    _ => return false,
+                };
+
+                event.prevent_default();
+                self.focus_pending = true;
+                self.update(TabsMsg::Activate(next)) || next == current
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props == props {
+            return false;
+        }
+
+        if self.props.tabs.len() != props.tabs.len() {
+            self.tab_refs = Self::tab_refs(&props);
+        }
+
+        if let Some(id) = &props.id {
+            self.id = id.clone();
+        }
+
+        if self.props.active != props.active || self.props.tabs != props.tabs {
+            self.active = Self::active_index(&props);
+        }
+
+        self.props = props;
+        true
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if self.focus_pending {
+            self.focus_pending = false;
+            if let Some(tab_ref) = self.active.and_then(|index| self.tab_refs.get(index)) {
+                focus_node(tab_ref);
+            }
+        }
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["tabs".to_string()];
+        classes.extend(self.props.size.to_class());
+
+        let flags = [
+            (self.props.is_boxed, "is-boxed"),
+            (self.props.is_toggle, "is-toggle"),
+            (self.props.is_centered, "is-centered"),
+            (self.props.is_fullwidth, "is-fullwidth"),
+        ];
+
+        classes.extend(
+            flags
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, class)| class.to_string()),
+        );
+
+        html! {
+            <div class=classes>
+                <ul role="tablist" aria-label=self.props.aria_label.clone()>
+                    {
+                        for self.props.tabs
+                            .iter()
+                            .enumerate()
+                            .map(|(index, tab)| self.view_tab(index, tab))
+                    }
+                </ul>
+            </div>
+        }
+    }
+}
+
+/// A panel in [TabPanels], which is displayed when the tab with the
+/// same `value` is active.
+#[derive(Debug)]
+pub struct TabPanel<T: PartialEq + Clone + 'static> {
+    props: TabPanelProps<T>,
+}
+
+/// [Properties](yew::Component::Properties) for [TabPanel].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct TabPanelProps<T: Clone> {
+    /// The value of the [Tab] which displays this panel.
+    pub value: T,
+    /// The content of the panel.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl<T> Component for TabPanel<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    type Message = ();
+    type Properties = TabPanelProps<T>;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! { <>{ self.props.children.clone() }</> }
+    }
+}
+
+/// `TabPanels` component, which renders only the [TabPanel] whose
+/// value matches `active`, or the first [TabPanel] if there is no
+/// such panel.
+///
+/// The panels are linked to the tabs of the [Tabs] whose `id` is
+/// `tabs_id`, for which the panels must be in the same order as the
+/// tabs.
+#[derive(Debug)]
+pub struct TabPanels<T: PartialEq + Clone + 'static> {
+    props: TabPanelsProps<T>,
+}
+
+/// [Properties](yew::Component::Properties) for [TabPanels].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct TabPanelsProps<T: PartialEq + Clone + 'static> {
+    /// The value of the active tab. If this is `None` the first panel
+    /// is displayed, matching the default active tab of [Tabs].
+    pub active: Option<T>,
+    /// The panels, one for each tab.
+    pub children: ChildrenWithProps<TabPanel<T>>,
+    /// (Optional) The `id` of the [Tabs] which control these panels.
+    #[prop_or_default]
+    pub tabs_id: Option<String>,
+}
+
+impl<T> Component for TabPanels<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    type Message = ();
+    type Properties = TabPanelsProps<T>;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        // Fall back to the first panel, like `Tabs::active_index`.
+        let panel = self
+            .props
+            .active
+            .as_ref()
+            .and_then(|active| {
+                self.props
+                    .children
+                    .iter()
+                    .enumerate()
+                    .find(|(_, panel)| &panel.props.value == active)
+            })
+            .or_else(|| self.props.children.iter().next().map(|panel| (0, panel)));
+
+        match panel {
+            Some((index, panel)) => {
+                let id = self.props.tabs_id.as_ref().map(|id| panel_id(id, index));
+                let labelledby = self.props.tabs_id.as_ref().map(|id| tab_id(id, index));
+                html! {
+                    <div role="tabpanel" id=id aria-labelledby=labelledby tabindex="0">
+                        { panel }
+                    </div>
+                }
+            }
+            None => html! {},
+        }
+    }
+}
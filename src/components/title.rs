@@ -0,0 +1,81 @@
+//! A `yew` [Component](yew::Component) to render a `bulma`
+//! [title or subtitle](https://bulma.io/documentation/elements/title/).
+
+use crate::classes::TitleSize;
+
+use yew::{html, Children, Component, ComponentLink, Html, Properties, ShouldRender};
+use yewtil::NeqAssign;
+
+/// `Title` component, which displays its children as a `title` or
+/// `subtitle`.
+#[derive(Debug)]
+pub struct Title {
+    props: Props,
+}
+
+/// [Properties](yew::Component::Properties) for [Title].
+#[derive(PartialEq, Clone, Properties, Debug)]
+pub struct Props {
+    /// The text of the title.
+    #[prop_or_default]
+    pub children: Children,
+    /// (Optional) Whether to display a `subtitle` instead of a
+    /// `title`. By default this is `false`.
+    #[prop_or(false)]
+    pub is_subtitle: bool,
+    /// (Optional) The size of the title. By default this is `None`,
+    /// which is displayed as [TitleSize::Is3] for a title and
+    /// [TitleSize::Is5] for a subtitle.
+    #[prop_or_default]
+    pub size: Option<TitleSize>,
+    /// (Optional) The name of the element to render. By default a
+    /// title is rendered as the heading matching its size, such as
+    /// `h3` for [TitleSize::Is3], and a subtitle as a `p`.
+    #[prop_or_default]
+    pub tag: Option<String>,
+    /// (Optional) Extra classes to apply to the title.
+    #[prop_or_default]
+    pub classes: Vec<String>,
+}
+
+impl Component for Title {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props.neq_assign(props)
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec![if self.props.is_subtitle {
+            "subtitle".to_string()
+        } else {
+            "title".to_string()
+        }];
+        classes.extend(self.props.size.map(|size| size.to_class()));
+        classes.extend(self.props.classes.clone());
+
+        let tag = match &self.props.tag {
+            Some(tag) => tag.clone(),
+            None if self.props.is_subtitle => "p".to_string(),
+            None => {
+                let size = self.props.size.unwrap_or(TitleSize::Is3);
+                format!("h{}", size.number())
+            }
+        };
+
+        html! {
+            <@{tag} class=classes>
+                { self.props.children.clone() }
+            </@>
+        }
+    }
+}